@@ -0,0 +1,271 @@
+//! Generates the registry of solutions that is linked into the main binary.
+//!
+//! Every `src/bin/<year>-<day>.rs` file is included as a module, so `cargo all` and `cargo time`
+//! can call the solutions in-process instead of spawning one `cargo run` per day.
+//!
+//! Also generates the example tests of every day from `data/<year>/examples/answers.json`, which
+//! `solution!` includes into the day's binary. The data directory is read from `aoc.toml`.
+use std::{
+    collections::HashMap,
+    env, fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    process,
+    str::FromStr,
+};
+use tinyjson::JsonValue;
+
+#[path = "src/template/events.rs"]
+mod events;
+
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+    println!("cargo:rerun-if-changed={}", bin_dir.display());
+
+    let mut days: Vec<(String, String, String)> = fs::read_dir(&bin_dir)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .map(|entry| entry.path())
+                .filter_map(|path| {
+                    let stem = path.file_stem()?.to_str()?;
+                    let (year, day) = stem.split_once('-')?;
+                    let is_day = path.extension()? == "rs"
+                        && year.len() == 4
+                        && day.len() == 2
+                        && (year.to_string() + day).chars().all(|c| c.is_ascii_digit());
+                    is_day.then(|| {
+                        (
+                            year.to_string(),
+                            day.to_string(),
+                            path.display().to_string(),
+                        )
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    days.sort();
+
+    let config = read_config(Path::new(&manifest_dir));
+
+    // a day after the last day of its event would compile and only be rejected by the commands.
+    let mut invalid: Vec<String> = days
+        .iter()
+        .filter_map(|(year, day, path)| {
+            let count = match event_days(&config, year) {
+                Ok(count) => count,
+                Err(e) => return Some(format!("invalid config, {e}")),
+            };
+            (day.parse::<u8>().unwrap() > count).then(|| {
+                format!(
+                    "{path}: day {day} is not part of {year}, which has {count} days. \
+                     Set the number of days in the `[days]` table of `aoc.toml` if this is wrong."
+                )
+            })
+        })
+        .collect();
+    // an invalid config is reported once per year instead of once per day.
+    invalid.dedup();
+
+    if !invalid.is_empty() {
+        for error in invalid {
+            eprintln!("error: {error}");
+        }
+        process::exit(1);
+    }
+
+    let modules: String = days
+        .iter()
+        .map(|(year, day, path)| {
+            format!("    #[allow(dead_code)]\n    #[path = {path:?}]\n    mod day_{year}_{day};\n")
+        })
+        .collect();
+
+    let solutions: String = days
+        .iter()
+        .map(|(year, day, _)| format!("        day_{year}_{day}::SOLUTION,\n"))
+        .collect();
+
+    // solutions are left out of test builds, where their tests already run as part of the
+    // day binaries, and of heap profiling builds, which allow only one global allocator.
+    let registry = format!(
+        "#[cfg(not(any(test, feature = \"dhat-heap\")))]
+mod days {{
+{modules}
+    pub const SOLUTIONS: &[advent_of_code::template::runner::Solution] = &[
+{solutions}    ];
+}}
+
+#[cfg(any(test, feature = \"dhat-heap\"))]
+mod days {{
+    pub const SOLUTIONS: &[advent_of_code::template::runner::Solution] = &[];
+}}
+
+pub use days::SOLUTIONS;
+"
+    );
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("registry.rs"), registry).unwrap();
+
+    let examples_dir = Path::new(&out_dir).join("examples");
+    fs::create_dir_all(&examples_dir).unwrap();
+
+    let mut years: Vec<&String> = days.iter().map(|(year, ..)| year).collect();
+    years.dedup();
+
+    let data_dir = data_dir(Path::new(&manifest_dir), &config);
+
+    let answers: HashMap<&String, Vec<(u8, u8, u8, String)>> = years
+        .into_iter()
+        .map(|year| {
+            let answers_path = data_dir.join(year).join("examples").join("answers.json");
+            println!("cargo:rerun-if-changed={}", answers_path.display());
+
+            // only a missing file means that the year has no examples yet.
+            let answers = match fs::read_to_string(&answers_path) {
+                Ok(json) => read_answers(&json),
+                Err(e) if e.kind() == ErrorKind::NotFound => Ok(vec![]),
+                Err(e) => Err(e.to_string()),
+            };
+
+            let answers = answers.unwrap_or_else(|e| {
+                eprintln!(
+                    "error: Failed to read answers from \"{}\": {e}",
+                    answers_path.display()
+                );
+                process::exit(1);
+            });
+            (year, answers)
+        })
+        .collect();
+
+    // one file per day, named like `solution!` refers to the day, e.g. `2024-7.rs`.
+    for (year, day, _) in &days {
+        let day: u8 = day.parse().unwrap();
+        let tests: String = answers[year]
+            .iter()
+            .filter(|(d, ..)| *d == day)
+            .map(|(_, example, part, answer)| {
+                format!(
+                    "#[test]\nfn example_{example}_part_{part}() {{\n    check({example}, {part}, {answer:?});\n}}\n\n"
+                )
+            })
+            .collect();
+
+        fs::write(examples_dir.join(format!("{year}-{day}.rs")), tests).unwrap();
+    }
+}
+
+/// The contents of `aoc.toml`, or of the file in `AOC_CONFIG`. Empty if it is missing or invalid,
+/// the template reports an invalid file when it runs.
+fn read_config(manifest_dir: &Path) -> toml::Table {
+    let config_path = env::var("AOC_CONFIG").map_or_else(
+        |_| manifest_dir.join("aoc.toml"),
+        |path| manifest_dir.join(path),
+    );
+    println!("cargo:rerun-if-changed={}", config_path.display());
+    println!("cargo:rerun-if-env-changed=AOC_CONFIG");
+
+    fs::read_to_string(&config_path)
+        .ok()
+        .and_then(|document| toml::from_str(&document).ok())
+        .unwrap_or_default()
+}
+
+/// The data directory of `aoc.toml`, overridden by `AOC_DATA_DIR` like in the template.
+fn data_dir(manifest_dir: &Path, config: &toml::Table) -> PathBuf {
+    println!("cargo:rerun-if-env-changed=AOC_DATA_DIR");
+
+    let configured = env::var("AOC_DATA_DIR")
+        .ok()
+        .or_else(|| Some(config.get("data")?.get("dir")?.as_str()?.to_string()));
+
+    manifest_dir.join(configured.unwrap_or_else(|| "data".into()))
+}
+
+/// Number of days of an event from `AOC_DAYS_<year>` or the `[days]` table of `aoc.toml`,
+/// checked and defaulted like in the template.
+fn event_days(config: &toml::Table, year: &str) -> Result<u8, String> {
+    let key = events::key(year);
+    let var = events::var(year);
+    println!("cargo:rerun-if-env-changed={var}");
+
+    let configured = match env::var(&var).ok().filter(|value| !value.is_empty()) {
+        Some(value) => Some(
+            value
+                .trim()
+                .parse()
+                .map_err(|_| format!("`{var}` must be an integer, got `{value}`"))?,
+        ),
+        None => config
+            .get("days")
+            .and_then(|days| days.get(year))
+            .map(|count| {
+                count
+                    .as_integer()
+                    .ok_or_else(|| format!("`{key}` must be an integer"))
+            })
+            .transpose()?,
+    };
+
+    let configured = configured
+        .map(|count| events::check(&key, count))
+        .transpose()?;
+    Ok(events::days(year.parse().unwrap(), configured))
+}
+
+/// Read `(day, example, part, answer)` entries from the expected answers of the examples.
+/// Fails on the first malformed entry, like the runner does when reading the file.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn read_answers(json: &str) -> Result<Vec<(u8, u8, u8, String)>, String> {
+    let json = JsonValue::from_str(json).or(Err("not valid JSON file."))?;
+
+    let data = json
+        .get::<HashMap<String, JsonValue>>()
+        .ok_or("expected JSON document to be an object.")?
+        .get("data")
+        .ok_or("expected JSON document to have key `data`.")?
+        .get::<Vec<JsonValue>>()
+        .ok_or("expected `json.data` to be an array.")?;
+
+    let mut answers: Vec<(u8, u8, u8, String)> = data
+        .iter()
+        .map(|entry| {
+            let entry = entry
+                .get::<HashMap<String, JsonValue>>()
+                .ok_or("Expected example answer to be a JSON object.")?;
+            let string = |key: &str| {
+                entry
+                    .get(key)
+                    .and_then(|v| v.get::<String>())
+                    .ok_or(format!("Expected example.{key} to be a string."))
+            };
+            let number = |key: &str| {
+                entry
+                    .get(key)
+                    .and_then(|v| v.get::<f64>())
+                    .map(|n| *n as u8)
+                    .ok_or(format!("Expected example.{key} to be a number."))
+            };
+
+            let day = string("day")?
+                .parse()
+                .or(Err("Expected example.day to be a day."))?;
+
+            Ok((
+                day,
+                number("example")?,
+                number("part")?,
+                string("answer")?.clone(),
+            ))
+        })
+        .collect::<Result<_, String>>()?;
+
+    answers.sort();
+    answers.dedup_by(|a, b| a.0 == b.0 && a.1 == b.1 && a.2 == b.2);
+    Ok(answers)
+}
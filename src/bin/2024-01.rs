@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-advent_of_code::solution!(1);
+advent_of_code::solution!(2024, day = 1);
 
 fn parse_input(input: &str) -> (Vec<i32>, Vec<i32>) {
     let (mut lhs, mut rhs): (Vec<i32>, Vec<i32>) = input
@@ -43,20 +43,3 @@ pub fn part_two(input: &str) -> Option<u32> {
 
     Some(result as u32)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(11));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(31));
-    }
-}
@@ -1,4 +1,4 @@
-advent_of_code::solution!(4);
+advent_of_code::solution!(2024, day = 4);
 
 fn count_xmas_occurrences(grid: &[Vec<char>], directions: &[(i32, i32)], word: &str) -> usize {
     let rows = grid.len();
@@ -85,20 +85,3 @@ pub fn part_two(input: &str) -> Option<u32> {
     let count = count_xmas_patterns(&grid);
     Some(count as u32)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(18));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(9));
-    }
-}
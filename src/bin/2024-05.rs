@@ -1,6 +1,6 @@
 use std::collections::{HashMap, HashSet};
 
-advent_of_code::solution!(5);
+advent_of_code::solution!(2024, day = 5);
 
 // Check if the update satisfies the ordering rules
 fn is_valid_update(update: &[i32], rules: &[(i32, i32)]) -> bool {
@@ -28,7 +28,7 @@ fn reorder_update(update: &[i32], rules: &[(i32, i32)]) -> Vec<i32> {
     let update_set: HashSet<_> = update.iter().cloned().collect();
     for &(x, y) in rules {
         if update_set.contains(&x) && update_set.contains(&y) {
-            graph.entry(x).or_insert_with(HashSet::new).insert(y);
+            graph.entry(x).or_default().insert(y);
             *in_degree.entry(y).or_insert(0) += 1;
             in_degree.entry(x).or_insert(0);
         }
@@ -116,20 +116,3 @@ pub fn part_two(input: &str) -> Option<u32> {
 
     Some(total_middle_sum)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(143));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(123));
-    }
-}
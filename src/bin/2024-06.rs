@@ -1,6 +1,6 @@
 use advent_of_code::Direction;
 
-advent_of_code::solution!(6);
+advent_of_code::solution!(2024, day = 6);
 
 #[derive(Clone)]
 struct Lab {
@@ -19,7 +19,7 @@ impl Lab {
         // Find the initial position and direction of the guard
         for (row, line) in map.iter().enumerate() {
             for (col, &cell) in line.iter().enumerate() {
-                if let Some(d) = Direction::try_from(cell).ok() {
+                if let Ok(d) = Direction::try_from(cell) {
                     guard_position = (col, row, d);
                     break;
                 }
@@ -91,20 +91,3 @@ pub fn part_two(input: &str) -> Option<u32> {
 
     Some(valid_obstructions as u32)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(41));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(6));
-    }
-}
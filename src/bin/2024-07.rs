@@ -1,4 +1,4 @@
-advent_of_code::solution!(7);
+advent_of_code::solution!(2024, day = 7);
 
 fn guess_operator(nums: &[usize], result: usize, operations: &[char]) -> bool {
     for op in operations {
@@ -57,20 +57,3 @@ pub fn part_one(input: &str) -> Option<usize> {
 pub fn part_two(input: &str) -> Option<usize> {
     solve(input, &['+', '*', 'c'])
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(3749));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(11387));
-    }
-}
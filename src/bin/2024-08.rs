@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-advent_of_code::solution!(8);
+advent_of_code::solution!(2024, day = 8);
 
 struct City {
     map: Vec<Vec<char>>,
@@ -87,20 +87,3 @@ pub fn part_two(input: &str) -> Option<u32> {
     city.calculate_antinodes(true);
     Some(city.antinodes() as u32)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(14));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(34));
-    }
-}
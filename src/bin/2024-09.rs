@@ -1,6 +1,6 @@
 use std::collections::BTreeMap;
 
-advent_of_code::solution!(9);
+advent_of_code::solution!(2024, day = 9);
 
 fn parse_blocks(raw_input: &str) -> Vec<Option<usize>> {
     let mut blocks = Vec::new();
@@ -27,7 +27,10 @@ fn parse_blocks(raw_input: &str) -> Vec<Option<usize>> {
     blocks
 }
 
-fn parse_files_and_free_spaces(raw_input: &str) -> (Vec<(usize, usize)>, Vec<(usize, usize)>) {
+/// A contiguous span of blocks as `(start, size)`.
+type Span = (usize, usize);
+
+fn parse_files_and_free_spaces(raw_input: &str) -> (Vec<Span>, Vec<Span>) {
     let mut file_layout = Vec::new();
     let mut free_space_layout = Vec::new();
 
@@ -67,7 +70,7 @@ pub fn part_one(input: &str) -> Option<usize> {
             blocks[block_id] = Some(file_id);
         }
     }
-    Some(checksum as usize)
+    Some(checksum)
 }
 
 pub fn part_two(input: &str) -> Option<usize> {
@@ -106,24 +109,7 @@ pub fn part_two(input: &str) -> Option<usize> {
         }
 
         // use arithmetic series formula for checksum
-        checksum += (file_id as usize) * (new_file_block_id * 2 + file_size - 1) * file_size / 2;
+        checksum += file_id * (new_file_block_id * 2 + file_size - 1) * file_size / 2;
     }
     Some(checksum)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(1928));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(2858));
-    }
-}
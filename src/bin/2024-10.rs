@@ -1,7 +1,7 @@
 use advent_of_code::util::grid::*;
 use advent_of_code::util::point::*;
 
-advent_of_code::solution!(10);
+advent_of_code::solution!(2024, day = 10, input = [Grid]);
 
 pub fn part_one(input: &str) -> Option<u32> {
     let grid = Grid::parse(input);
@@ -49,20 +49,3 @@ fn dfs(grid: &Grid<u8>, distinct: bool, seen: &mut Grid<i32>, id: i32, point: Po
 
     result
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(36));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(81));
-    }
-}
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-advent_of_code::solution!(11);
+advent_of_code::solution!(2024, day = 11);
 
 fn solve(input: &str, blinks: u32) -> u64 {
     let initial_stones = input
@@ -47,20 +47,3 @@ pub fn part_two(input: &str) -> Option<u64> {
     let res = solve(input, 75);
     Some(res)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(55312));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(65601038650482));
-    }
-}
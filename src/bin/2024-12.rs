@@ -1,7 +1,7 @@
 use advent_of_code::util::grid::*;
 use advent_of_code::util::point::*;
 
-advent_of_code::solution!(12);
+advent_of_code::solution!(2024, day = 12, input = [Grid], parse = parse);
 
 pub fn parse(input: &str) -> (usize, usize) {
     let grid = Grid::parse(input);
@@ -71,29 +71,12 @@ pub fn parse(input: &str) -> (usize, usize) {
     (part_one, part_two)
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let (part_one, _) = parse(input);
+pub fn part_one(input: &(usize, usize)) -> Option<u32> {
+    let (part_one, _) = *input;
     Some(part_one as u32)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let (_, part_two) = parse(input);
+pub fn part_two(input: &(usize, usize)) -> Option<u32> {
+    let (_, part_two) = *input;
     Some(part_two as u32)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(1930));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(1206));
-    }
-}
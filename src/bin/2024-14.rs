@@ -1,6 +1,6 @@
 use itertools::Itertools;
 
-advent_of_code::solution!(14);
+advent_of_code::solution!(2024, day = 14);
 
 const WIDTH: isize = 101;
 const HEIGHT: isize = 103;
@@ -83,20 +83,3 @@ pub fn part_two(input: &str) -> Option<usize> {
         .0;
     Some(result)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(21));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(69));
-    }
-}
@@ -1,6 +1,6 @@
 use advent_of_code::Direction;
 
-advent_of_code::solution!(15);
+advent_of_code::solution!(2024, day = 15);
 
 #[derive(Debug)]
 struct Warehouse {
@@ -40,14 +40,13 @@ impl Warehouse {
             .iter()
             .map(|row| {
                 row.iter()
-                    .map(|cell| match cell {
+                    .flat_map(|cell| match cell {
                         '#' => vec!['#', '#'],
                         '.' => vec!['.', '.'],
                         'O' => vec!['[', ']'],
                         '@' => vec!['@', '.'],
                         _ => vec![],
                     })
-                    .flatten()
                     .collect()
             })
             .collect();
@@ -145,7 +144,7 @@ impl Warehouse {
     }
 }
 
-fn find_robot(grid: &Vec<Vec<char>>) -> (usize, usize) {
+fn find_robot(grid: &[Vec<char>]) -> (usize, usize) {
     let mut robot = (0, 0);
     for (row, line) in grid.iter().enumerate() {
         for (col, &cell) in line.iter().enumerate() {
@@ -169,30 +168,3 @@ pub fn part_two(input: &str) -> Option<usize> {
     warehouse.patrol();
     Some(warehouse.gps())
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(2028));
-    }
-
-    #[test]
-    fn test_part_one_big() {
-        let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
-        ));
-        assert_eq!(result, Some(10092));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
-        ));
-        assert_eq!(result, Some(9021));
-    }
-}
@@ -3,7 +3,7 @@ use std::collections::VecDeque;
 use advent_of_code::util::grid::*;
 use advent_of_code::util::point::*;
 
-advent_of_code::solution!(16);
+advent_of_code::solution!(2024, day = 16, parse = parse);
 
 /// Clockwise order starting with facing right.
 const DIRECTIONS: [Point; 4] = [RIGHT, DOWN, LEFT, UP];
@@ -99,29 +99,12 @@ pub fn parse(input: &str) -> (u32, usize) {
     (lowest, path.bytes.iter().filter(|&&b| b).count())
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let (lowest, _) = parse(input);
+pub fn part_one(input: &(u32, usize)) -> Option<u32> {
+    let (lowest, _) = *input;
     Some(lowest)
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
-    let (_, path_len) = parse(input);
+pub fn part_two(input: &(u32, usize)) -> Option<usize> {
+    let (_, path_len) = *input;
     Some(path_len)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(7036));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(45));
-    }
-}
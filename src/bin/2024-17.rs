@@ -1,4 +1,4 @@
-advent_of_code::solution!(17);
+advent_of_code::solution!(2024, day = 17);
 
 struct Computer<'a> {
     program: &'a [u64],
@@ -48,7 +48,7 @@ impl Computer<'_> {
 }
 
 pub fn parse(input: &str) -> Vec<u64> {
-    input.split(|c: char| !c.is_digit(10)) // Split on non-digits
+    input.split(|c: char| !c.is_ascii_digit()) // Split on non-digits
         .filter(|s| !s.is_empty()) // Remove empty strings
         .filter_map(|s| s.parse::<u64>().ok()) // Parse and filter out errors
         .collect()
@@ -97,21 +97,3 @@ pub fn part_two(input: &str) -> Option<u64> {
 
     valid.iter().min().copied()
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let binding = advent_of_code::template::read_file("examples", DAY);
-        let result = part_one(&binding);
-        assert_eq!(result, Some("4,6,3,5,6,3,5,2,1,0".to_string()));
-    }
-    #[test]
-    fn test_part_two() {
-        let binding = advent_of_code::template::read_file_part("examples", DAY, 2);
-        let result = part_two(&binding);
-        assert_eq!(result, Some(117440));
-    }
-}
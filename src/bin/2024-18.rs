@@ -3,7 +3,7 @@ use std::collections::VecDeque;
 use advent_of_code::util::grid::*;
 use advent_of_code::util::point::*;
 
-advent_of_code::solution!(18);
+advent_of_code::solution!(2024, day = 18);
 
 const ORIGIN: Point = Point::new(0, 0);
 
@@ -16,14 +16,14 @@ fn parse(input: &str, grid_size: i32) -> Grid<u16> {
     grid
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
+pub fn part_one(input: &str) -> Result<u32, &'static str> {
     let grid = parse(input, 71);
-    bfs(&grid, 1024)
+    bfs(&grid, 1024).ok_or("the exit cannot be reached after 1024 bytes")
 }
 
-pub fn part_two(input: &str) -> Option<String> {
+pub fn part_two(input: &str) -> Result<String, &'static str> {
     let grid = parse(input, 71);
-    find_choke_point(&grid)
+    find_choke_point(&grid).ok_or("no byte blocks the path to the exit")
 }
 
 fn bfs(grid: &Grid<u16>, time: u16) -> Option<u32> {
@@ -55,7 +55,7 @@ fn find_choke_point(grid: &Grid<u16>) -> Option<String> {
 
     while lower < upper {
         let middle = (lower + upper) / 2;
-        if bfs(&grid, middle).is_some() {
+        if bfs(grid, middle).is_some() {
             lower = middle + 1;
         } else {
             upper = middle;
@@ -74,7 +74,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = &advent_of_code::template::read_file("examples", DAY);
+        let input = &advent_of_code::template::read_file("examples", PUZZLE);
         let grid = parse(input, 7);
         let result = bfs(&grid, 12);
         assert_eq!(result, Some(22));
@@ -82,7 +82,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let input = &advent_of_code::template::read_file("examples", DAY);
+        let input = &advent_of_code::template::read_file("examples", PUZZLE);
         let grid = parse(input, 7);
         let result = find_choke_point(&grid);
         assert_eq!(result, Some("6,1".to_string()));
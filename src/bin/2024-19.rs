@@ -1,4 +1,4 @@
-advent_of_code::solution!(19);
+advent_of_code::solution!(2024, day = 19, input = [Sections(2)], parse = parse);
 
 fn parse(input: &str) -> Vec<usize> {
     let (prefix, suffix) = input.split_once("\n\n").unwrap();
@@ -9,13 +9,13 @@ fn parse(input: &str) -> Vec<usize> {
         .collect()
 }
 
-pub fn part_one(input: &str) -> Option<usize> {
-    let possible_designs = parse(input).iter().filter(|c| **c > 0).count();
+pub fn part_one(ways: &[usize]) -> Option<usize> {
+    let possible_designs = ways.iter().filter(|c| **c > 0).count();
     Some(possible_designs)
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
-    let total_ways = parse(input).iter().sum();
+pub fn part_two(ways: &[usize]) -> Option<usize> {
+    let total_ways = ways.iter().sum();
     Some(total_ways)
 }
 
@@ -36,20 +36,3 @@ fn count_ways_dp(design: &str, patterns: &[&str]) -> usize {
 
     dp[n]
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(6));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(16));
-    }
-}
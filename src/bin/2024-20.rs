@@ -5,7 +5,7 @@ use advent_of_code::util::grid::*;
 use advent_of_code::util::point::*;
 use advent_of_code::util::thread::*;
 
-advent_of_code::solution!(20);
+advent_of_code::solution!(2024, day = 20, parse = parse);
 
 fn parse(input: &str) -> Grid<i32> {
     let grid = Grid::parse(input);
@@ -41,8 +41,8 @@ fn count_cheats(time: &Grid<i32>, savings: u32) -> u32 {
             let point = Point::new(x, y);
 
             if time[point] != i32::MAX {
-                cheats += check(&time, point, Point::new(2, 0), savings);
-                cheats += check(&time, point, Point::new(0, 2), savings);
+                cheats += check(time, point, Point::new(2, 0), savings);
+                cheats += check(time, point, Point::new(0, 2), savings);
             }
         }
     }
@@ -64,19 +64,17 @@ fn count_cheats_p2(time: &Grid<i32>, savings: u32) -> u32 {
     }
 
     let total = AtomicU32::new(0);
-    spawn_batches(items, |batch| worker(&time, &total, batch, savings));
+    spawn_batches(items, |batch| worker(time, &total, batch, savings));
     total.into_inner()
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let time = parse(input);
-    let cheats = count_cheats(&time, 100);
+pub fn part_one(time: &Grid<i32>) -> Option<u32> {
+    let cheats = count_cheats(time, 100);
     Some(cheats)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let time = parse(input);
-    let res = count_cheats_p2(&time, 100);
+pub fn part_two(time: &Grid<i32>) -> Option<u32> {
+    let res = count_cheats_p2(time, 100);
     Some(res)
 }
 
@@ -112,7 +110,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::template::read_file("examples", DAY);
+        let input = advent_of_code::template::read_file("examples", PUZZLE);
         let time = parse(&input);
         let result = count_cheats(&time, 64);
         assert_eq!(result, 1);
@@ -120,7 +118,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::template::read_file("examples", DAY);
+        let input = advent_of_code::template::read_file("examples", PUZZLE);
         let time = parse(&input);
         let result = count_cheats_p2(&time, 76);
         assert_eq!(result, 3);
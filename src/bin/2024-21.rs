@@ -1,4 +1,4 @@
-advent_of_code::solution!(21);
+advent_of_code::solution!(2024, day = 21);
 
 use std::collections::{HashMap, VecDeque};
 
@@ -148,20 +148,3 @@ pub fn part_one(input: &str) -> Option<usize> {
 pub fn part_two(input: &str) -> Option<usize> {
     solve(input, 25)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(126384));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(154115708116294));
-    }
-}
@@ -1,4 +1,4 @@
-advent_of_code::solution!(22);
+advent_of_code::solution!(2024, day = 22);
 
 fn parse(input: &str) -> Vec<usize> {
     input
@@ -76,22 +76,3 @@ pub fn part_two(input: &str) -> Option<usize> {
     let result = *result.iter().max().unwrap();
     Some(result)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(37327623));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
-        ));
-        assert_eq!(result, Some(23));
-    }
-}
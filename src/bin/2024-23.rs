@@ -1,13 +1,13 @@
 use std::collections::{HashMap, HashSet};
 
-advent_of_code::solution!(23);
+advent_of_code::solution!(2024, day = 23);
 
 fn parse(input: &str) -> HashMap<&str, HashSet<&str>> {
     let mut connections: HashMap<&str, HashSet<&str>> = HashMap::new();
     for line in input.lines().filter(|l| !l.is_empty()) {
         let (a, b) = line.split_once("-").unwrap();
-        connections.entry(a).or_insert_with(HashSet::new).insert(b);
-        connections.entry(b).or_insert_with(HashSet::new).insert(a);
+        connections.entry(a).or_default().insert(b);
+        connections.entry(b).or_default().insert(a);
     }
     connections
 }
@@ -26,7 +26,7 @@ pub fn part_one(input: &str) -> Option<usize> {
                     if v < w
                         && adj_set
                             .get(v)
-                            .map_or(false, |neighbors_v| neighbors_v.contains(w))
+                            .is_some_and(|neighbors_v| neighbors_v.contains(w))
                     {
                         // Triangle found (u, v, w)
                         if u.starts_with('t') || v.starts_with('t') || w.starts_with('t') {
@@ -77,11 +77,7 @@ pub fn part_two(input: &str) -> Option<String> {
             .iter()
             .max_by_key(|&u| {
                 p.iter()
-                    .filter(|&v| {
-                        graph
-                            .get(u)
-                            .map_or(false, |neighbors| neighbors.contains(v))
-                    })
+                    .filter(|&v| graph.get(u).is_some_and(|neighbors| neighbors.contains(v)))
                     .count()
             })
             .cloned()
@@ -92,7 +88,7 @@ pub fn part_two(input: &str) -> Option<String> {
             .filter(|&v| {
                 !graph
                     .get(pivot)
-                    .map_or(false, |neighbors| neighbors.contains(v))
+                    .is_some_and(|neighbors| neighbors.contains(v))
             })
             .cloned()
             .collect();
@@ -143,20 +139,3 @@ pub fn part_two(input: &str) -> Option<String> {
         Some(password)
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(7));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some("co,de,ka,ta".to_string()));
-    }
-}
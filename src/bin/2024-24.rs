@@ -1,6 +1,6 @@
 use std::collections::{HashSet, VecDeque};
 
-advent_of_code::solution!(24);
+advent_of_code::solution!(2024, day = 24, input = [Sections(2)]);
 
 fn parse(input: &str) -> (&str, Vec<Vec<&str>>) {
     let (prefix, suffix) = input.split_once("\n\n").unwrap();
@@ -106,22 +106,3 @@ pub fn part_two(input: &str) -> Option<String> {
     result.sort_unstable();
     Some(result.join(","))
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(2024));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
-        ));
-        assert_eq!(result, Some("z00,z01,z02,z03,z04".to_string()));
-    }
-}
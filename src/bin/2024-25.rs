@@ -1,4 +1,4 @@
-advent_of_code::solution!(25);
+advent_of_code::solution!(2024, day = 25);
 
 const MASK: u64 = 0b011111_011111_011111_011111_011111_011111_011111;
 
@@ -38,15 +38,10 @@ pub fn part_two(_: &str) -> Option<u32> {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(3));
-    }
-
+    // the last day has no second part, which the expected answers of the examples cannot express.
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, None);
     }
 }
@@ -1,41 +1,100 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::scaffold::DEFAULT_RETURN_TYPE;
+use advent_of_code::template::commands::time::{TimeArgs, DEFAULT_THRESHOLD};
+use advent_of_code::template::commands::{
+    all, config, decrypt, download, encrypt, examples, migrate, read, scaffold, solve, status,
+    time, verify,
+};
+use advent_of_code::template::{Error, Mode};
 use args::{parse, AppArguments};
+use std::process;
+use std::time::Duration;
 
 #[cfg(feature = "today")]
-use advent_of_code::template::Day;
-#[cfg(feature = "today")]
-use std::process;
+use advent_of_code::template::Puzzle;
+
+/// Solutions of every day, linked into this binary by the build script.
+mod registry {
+    include!(concat!(env!("OUT_DIR"), "/registry.rs"));
+}
 
 mod args {
-    use advent_of_code::template::Day;
-    use std::process;
+    use advent_of_code::template::runner::InputSource;
+    use advent_of_code::template::{config, Day, Format, Puzzle, Year};
+    use std::path::PathBuf;
 
     pub enum AppArguments {
         Download {
-            day: Day,
+            puzzle: Puzzle,
         },
         Read {
-            day: Day,
+            puzzle: Puzzle,
+        },
+        Examples {
+            puzzle: Puzzle,
+            choose: bool,
+            overwrite: bool,
         },
         Scaffold {
-            day: Day,
+            puzzle: Puzzle,
             download: bool,
             overwrite: bool,
+            template: Option<String>,
+            return_type: Option<String>,
         },
         Solve {
-            day: Day,
+            puzzle: Puzzle,
             release: bool,
             dhat: bool,
             submit: Option<u8>,
+            format: Format,
+            input: InputSource,
         },
         All {
+            year: Year,
             release: bool,
+            subprocess: bool,
+            format: Format,
+            jobs: Option<usize>,
+            timeout: Option<u64>,
         },
         Time {
+            year: Year,
             all: bool,
             day: Option<Day>,
             store: bool,
+            subprocess: bool,
+            budget: Option<u64>,
+            format: Format,
+            threshold: Option<f64>,
+            fail_on_regression: bool,
+            timeout: Option<u64>,
+            memory: bool,
+        },
+        Verify {
+            year: Year,
+            day: Option<Day>,
+            release: bool,
+            store: bool,
+            subprocess: bool,
+            timeout: Option<u64>,
+        },
+        Migrate {
+            year: Year,
+        },
+        Encrypt {
+            year: Year,
+            day: Option<Day>,
+        },
+        Decrypt {
+            year: Year,
+            day: Option<Day>,
+            overwrite: bool,
+        },
+        Status {
+            year: Year,
+            json: bool,
         },
+        Config,
         #[cfg(feature = "today")]
         Today,
     }
@@ -43,47 +102,163 @@ mod args {
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
-        let app_args = match args.subcommand()?.as_deref() {
+        let subcommand = args.subcommand()?;
+
+        // every command works on the puzzles of one year, the configured year is used without `--year`.
+        let year: Option<Year> = args.opt_value_from_str("--year")?.or(config::get().year);
+        let year = || year.ok_or("no year given, pass `--year <year>` or set `year` in `aoc.toml`");
+
+        // days after the last day of the event are rejected, e.g. day 20 of a 12 day event.
+        let puzzle = |day: Day| -> Result<Puzzle, Box<dyn std::error::Error>> {
+            let year = year()?;
+            Puzzle::try_new(year, day).ok_or_else(|| {
+                format!(
+                    "day {day} is not part of {year}, which has {} days",
+                    year.days()
+                )
+                .into()
+            })
+        };
+
+        let app_args = match subcommand.as_deref() {
             Some("all") => AppArguments::All {
+                year: year()?,
                 release: args.contains("--release"),
+                subprocess: args.contains("--subprocess"),
+                format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+                jobs: args.opt_value_from_str("--jobs")?,
+                timeout: args.opt_value_from_str("--timeout")?,
             },
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let subprocess = args.contains("--subprocess");
+                let budget = args.opt_value_from_str("--budget")?;
+                let format = args.opt_value_from_str("--format")?.unwrap_or_default();
+                let threshold = args.opt_value_from_str("--threshold")?;
+                let fail_on_regression = args.contains("--fail-on-regression");
+                let timeout = args.opt_value_from_str("--timeout")?;
+                let memory = args.contains("--memory");
 
                 AppArguments::Time {
+                    year: year()?,
                     all,
-                    day: args.opt_free_from_str()?,
+                    day: args
+                        .opt_free_from_str()?
+                        .map(puzzle)
+                        .transpose()?
+                        .map(|p| p.day),
+                    store,
+                    subprocess,
+                    budget,
+                    format,
+                    threshold,
+                    fail_on_regression,
+                    timeout,
+                    memory,
+                }
+            }
+            Some("verify") => {
+                let release = args.contains("--release");
+                let store = args.contains("--store");
+                let subprocess = args.contains("--subprocess");
+                let timeout = args.opt_value_from_str("--timeout")?;
+
+                AppArguments::Verify {
+                    year: year()?,
+                    day: args
+                        .opt_free_from_str()?
+                        .map(puzzle)
+                        .transpose()?
+                        .map(|p| p.day),
+                    release,
                     store,
+                    subprocess,
+                    timeout,
                 }
             }
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+                puzzle: puzzle(args.free_from_str()?)?,
             },
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+                puzzle: puzzle(args.free_from_str()?)?,
+            },
+            Some("examples") => AppArguments::Examples {
+                puzzle: puzzle(args.free_from_str()?)?,
+                choose: args.contains("--choose"),
+                overwrite: args.contains("--overwrite"),
             },
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
+                puzzle: puzzle(args.free_from_str()?)?,
                 download: args.contains("--download"),
                 overwrite: args.contains("--overwrite"),
+                template: args.opt_value_from_str("--template")?,
+                return_type: args.opt_value_from_str("--return-type")?,
             },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
+            Some("solve") => {
+                let input_path: Option<PathBuf> = args.opt_value_from_str("--input")?;
+                let stdin = args.contains("--stdin");
+                let example = args.contains("--example");
+
+                let puzzle = puzzle(args.free_from_str()?)?;
+                let release = args.contains("--release");
+                let submit = args.opt_value_from_str("--submit")?;
+                let dhat = args.contains("--dhat");
+                let format = args.opt_value_from_str("--format")?.unwrap_or_default();
+
+                let input = match (input_path, stdin, example) {
+                    (Some(path), false, false) => InputSource::File(path),
+                    (None, true, false) => InputSource::Stdin,
+                    // the number of the example is optional and follows the day.
+                    (None, false, true) => InputSource::Examples(args.opt_free_from_str()?),
+                    (None, false, false) => InputSource::Default,
+                    _ => {
+                        return Err(
+                            "Only one of `--input`, `--stdin` and `--example` can be used.".into(),
+                        );
+                    }
+                };
+
+                AppArguments::Solve {
+                    puzzle,
+                    release,
+                    submit,
+                    dhat,
+                    format,
+                    input,
+                }
+            }
+            Some("migrate") => AppArguments::Migrate { year: year()? },
+            Some("encrypt") => AppArguments::Encrypt {
+                year: year()?,
+                day: args
+                    .opt_free_from_str()?
+                    .map(puzzle)
+                    .transpose()?
+                    .map(|p| p.day),
+            },
+            Some("decrypt") => {
+                let overwrite = args.contains("--overwrite");
+
+                AppArguments::Decrypt {
+                    year: year()?,
+                    day: args
+                        .opt_free_from_str()?
+                        .map(puzzle)
+                        .transpose()?
+                        .map(|p| p.day),
+                    overwrite,
+                }
+            }
+            Some("status") => AppArguments::Status {
+                year: year()?,
+                json: args.contains("--json"),
             },
+            Some("config") => AppArguments::Config,
             #[cfg(feature = "today")]
             Some("today") => AppArguments::Today,
-            Some(x) => {
-                eprintln!("Unknown command: {x}");
-                process::exit(1);
-            }
-            None => {
-                eprintln!("No command specified.");
-                process::exit(1);
-            }
+            Some(x) => return Err(format!("Unknown command: {x}").into()),
+            None => return Err("No command specified.".into()),
         };
 
         let remaining = args.finish();
@@ -95,50 +270,145 @@ mod args {
     }
 }
 
+fn get_mode(subprocess: bool) -> Mode<'static> {
+    if subprocess {
+        Mode::Subprocess
+    } else {
+        Mode::InProcess(registry::SOLUTIONS)
+    }
+}
+
 fn main() {
-    match parse() {
-        Err(err) => {
-            eprintln!("Error: {err}");
-            std::process::exit(1);
-        }
-        Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold {
-                day,
-                download,
+    if let Err(e) = run() {
+        eprintln!("Error: {e}");
+        process::exit(e.exit_code());
+    }
+}
+
+/// Run the command, the error decides the exit code of the process, see [`Error::exit_code`].
+fn run() -> Result<(), Error> {
+    advent_of_code::template::config::init()?;
+
+    match parse().map_err(|e| Error::Parse(e.to_string()))? {
+        AppArguments::All {
+            year,
+            release,
+            subprocess,
+            format,
+            jobs,
+            timeout,
+        } => all::handle(
+            year,
+            get_mode(subprocess),
+            release,
+            format,
+            jobs.unwrap_or(1),
+            timeout.map(Duration::from_secs),
+        ),
+        AppArguments::Time {
+            year,
+            day,
+            all,
+            store,
+            subprocess,
+            budget,
+            format,
+            threshold,
+            fail_on_regression,
+            timeout,
+            memory,
+        } => time::handle(
+            year,
+            get_mode(subprocess),
+            day,
+            &TimeArgs {
+                run_all: all,
+                store,
+                budget: budget.map_or_else(
+                    || advent_of_code::template::config::get().budget,
+                    Duration::from_millis,
+                ),
+                format,
+                threshold: threshold.unwrap_or(DEFAULT_THRESHOLD),
+                fail_on_regression,
+                timeout: timeout.map(Duration::from_secs),
+                memory,
+            },
+        ),
+        AppArguments::Verify {
+            year,
+            day,
+            release,
+            store,
+            subprocess,
+            timeout,
+        } => verify::handle(
+            year,
+            get_mode(subprocess),
+            day,
+            release,
+            store,
+            timeout.map(Duration::from_secs),
+        ),
+        AppArguments::Download { puzzle } => download::handle(puzzle),
+        AppArguments::Read { puzzle } => read::handle(puzzle),
+        AppArguments::Examples {
+            puzzle,
+            choose,
+            overwrite,
+        } => examples::handle(puzzle, choose, overwrite),
+        AppArguments::Scaffold {
+            puzzle,
+            download,
+            overwrite,
+            template,
+            return_type,
+        } => {
+            // the puzzle is downloaded first, so its title can be used in the module.
+            if download {
+                download::handle(puzzle)?;
+            }
+            scaffold::handle(
+                puzzle,
                 overwrite,
-            } => {
-                scaffold::handle(day, overwrite);
-                if download {
-                    download::handle(day);
-                }
+                template.as_deref(),
+                return_type.as_deref().unwrap_or(DEFAULT_RETURN_TYPE),
+            )?;
+            if download {
+                examples::handle(puzzle, false, overwrite)?;
             }
-            AppArguments::Solve {
-                day,
-                release,
-                dhat,
-                submit,
-            } => solve::handle(day, release, dhat, submit),
-            #[cfg(feature = "today")]
-            AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
-                    }
-                    None => {
-                        eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
-                        );
-                        process::exit(1)
-                    }
-                };
+            Ok(())
+        }
+        AppArguments::Solve {
+            puzzle,
+            release,
+            dhat,
+            submit,
+            format,
+            input,
+        } => solve::handle(puzzle, release, dhat, submit, format, &input),
+        AppArguments::Migrate { year } => migrate::handle(year),
+        AppArguments::Encrypt { year, day } => encrypt::handle(year, day),
+        AppArguments::Decrypt {
+            year,
+            day,
+            overwrite,
+        } => decrypt::handle(year, day, overwrite),
+        AppArguments::Status { year, json } => status::handle(year, registry::SOLUTIONS, json),
+        AppArguments::Config => config::handle(),
+        #[cfg(feature = "today")]
+        AppArguments::Today => match Puzzle::today() {
+            Some(puzzle) => {
+                download::handle(puzzle)?;
+                scaffold::handle(puzzle, false, None, DEFAULT_RETURN_TYPE)?;
+                examples::handle(puzzle, false, false)?;
+                read::handle(puzzle)
             }
+            None => Err(Error::Parse(
+                "`today` command can only be run on the days of the event in \
+                    december. Please use `scaffold` with a specific day."
+                    .into(),
+            )),
         },
-    };
+    }
 }
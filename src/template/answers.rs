@@ -0,0 +1,426 @@
+use std::{
+    collections::HashMap,
+    fs,
+    io::{self, ErrorKind},
+    str::FromStr,
+};
+use tinyjson::JsonValue;
+
+use crate::template::aoc_client::{Hint, Submission};
+use crate::template::{Day, Error, Year};
+
+/// Name of the file in the data directory of a year.
+static ANSWERS_FILE_NAME: &str = "answers.json";
+
+/// A rejected answer, with the hint the server gave for it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WrongAnswer {
+    pub value: String,
+    pub hint: Option<Hint>,
+}
+
+/// Represents the submission history of a single day and part.
+#[derive(Clone, Debug)]
+pub struct Answer {
+    pub day: Day,
+    pub part: u8,
+    pub correct: Option<String>,
+    pub wrong: Vec<WrongAnswer>,
+    /// Output of a local run, stored by `verify --store` without being submitted.
+    /// Unlike `correct`, it does not count as accepted when submitting.
+    pub expected: Option<String>,
+}
+
+/// Result of checking an answer against the recorded history before submitting it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Check {
+    /// Nothing is known about the answer.
+    Unknown,
+    /// The answer was already accepted.
+    Accepted,
+    /// A different answer was already accepted.
+    Solved(String),
+    /// The answer was already rejected.
+    Rejected(Option<Hint>),
+    /// The answer is not below a known "too high" or not above a known "too low" answer.
+    OutOfBounds(Hint, String),
+}
+
+/// Represents the submission history for a set of days.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Answers {
+    pub data: Vec<Answer>,
+}
+
+impl Answers {
+    /// Dehydrate answers to a JSON file.
+    pub fn store_file(&self, year: Year) -> Result<(), io::Error> {
+        let json = JsonValue::from(self.clone());
+        fs::create_dir_all(year.data_dir())?;
+        let mut file = fs::File::create(year.data_dir().join(ANSWERS_FILE_NAME))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate answers from a JSON file. If not present, returns empty answers.
+    /// Fails if the file cannot be read or parsed, so the history is not overwritten when storing.
+    pub fn read_from_file(year: Year) -> Result<Self, Error> {
+        let path = year.data_dir().join(ANSWERS_FILE_NAME);
+        let context = format!("Failed to read answers from \"{}\"", path.display());
+
+        match fs::read_to_string(&path) {
+            Ok(json) => Answers::try_from(json).map_err(Error::io(&context)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(Error::io(&context)(e)),
+        }
+    }
+
+    pub fn get(&self, day: Day, part: u8) -> Option<&Answer> {
+        self.data.iter().find(|a| a.day == day && a.part == part)
+    }
+
+    /// Check a candidate answer against the recorded submissions.
+    pub fn check(&self, day: Day, part: u8, value: &str) -> Check {
+        let Some(answer) = self.get(day, part) else {
+            return Check::Unknown;
+        };
+
+        if let Some(correct) = &answer.correct {
+            return if correct == value {
+                Check::Accepted
+            } else {
+                Check::Solved(correct.clone())
+            };
+        }
+
+        if let Some(wrong) = answer.wrong.iter().find(|w| w.value == value) {
+            return Check::Rejected(wrong.hint);
+        }
+
+        let Ok(number) = value.parse::<i128>() else {
+            return Check::Unknown;
+        };
+
+        let bound = |hint: Hint| {
+            answer
+                .wrong
+                .iter()
+                .filter(move |w| w.hint == Some(hint))
+                .filter_map(|w| w.value.parse::<i128>().ok())
+        };
+
+        if let Some(upper) = bound(Hint::TooHigh).min() {
+            if number >= upper {
+                return Check::OutOfBounds(Hint::TooHigh, upper.to_string());
+            }
+        }
+
+        if let Some(lower) = bound(Hint::TooLow).max() {
+            if number <= lower {
+                return Check::OutOfBounds(Hint::TooLow, lower.to_string());
+            }
+        }
+
+        Check::Unknown
+    }
+
+    /// The accepted answer of a part or, if none was submitted, the one stored by `verify --store`.
+    pub fn expected(&self, day: Day, part: u8) -> Option<&String> {
+        let answer = self.get(day, part)?;
+        answer.correct.as_ref().or(answer.expected.as_ref())
+    }
+
+    /// The history of a part, created if it does not exist yet.
+    fn entry(&mut self, day: Day, part: u8) -> &mut Answer {
+        let key = (day, part);
+        let index = match self.data.binary_search_by_key(&key, |a| (a.day, a.part)) {
+            Ok(index) => index,
+            Err(index) => {
+                let answer = Answer {
+                    day,
+                    part,
+                    correct: None,
+                    wrong: vec![],
+                    expected: None,
+                };
+                self.data.insert(index, answer);
+                index
+            }
+        };
+
+        &mut self.data[index]
+    }
+
+    /// Record the output of a local run as the expected answer, without marking it as accepted.
+    pub fn store_expected(&mut self, day: Day, part: u8, value: &str) {
+        self.entry(day, part).expected = Some(value.into());
+    }
+
+    /// Record the outcome of a submission.
+    pub fn record(&mut self, day: Day, part: u8, value: &str, submission: &Submission) {
+        let answer = self.entry(day, part);
+
+        match submission {
+            Submission::Correct => answer.correct = Some(value.into()),
+            Submission::Incorrect(hint) => {
+                if !answer.wrong.iter().any(|w| w.value == value) {
+                    answer.wrong.push(WrongAnswer {
+                        value: value.into(),
+                        hint: *hint,
+                    });
+                }
+            }
+            Submission::WrongLevel => {}
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Answers> for JsonValue {
+    fn from(value: Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Answers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        let mut data: Vec<Answer> = json_data
+            .iter()
+            .map(Answer::try_from)
+            .collect::<Result<_, _>>()?;
+        // `entry` looks parts up by binary search.
+        data.sort_unstable_by_key(|a| (a.day, a.part));
+
+        Ok(Answers { data })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn hint_to_json(hint: Option<Hint>) -> JsonValue {
+    match hint {
+        Some(Hint::TooHigh) => JsonValue::String("too_high".into()),
+        Some(Hint::TooLow) => JsonValue::String("too_low".into()),
+        None => JsonValue::Null,
+    }
+}
+
+fn hint_from_json(value: &JsonValue) -> Result<Option<Hint>, String> {
+    match value {
+        JsonValue::Null => Ok(None),
+        JsonValue::String(s) if s == "too_high" => Ok(Some(Hint::TooHigh)),
+        JsonValue::String(s) if s == "too_low" => Ok(Some(Hint::TooLow)),
+        _ => Err("Expected hint to be null, `too_high` or `too_low`.".into()),
+    }
+}
+
+impl From<&Answer> for JsonValue {
+    fn from(value: &Answer) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+
+        map.insert(
+            "correct".into(),
+            match &value.correct {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+
+        map.insert(
+            "wrong".into(),
+            JsonValue::Array(
+                value
+                    .wrong
+                    .iter()
+                    .map(|w| {
+                        let mut map: HashMap<String, JsonValue> = HashMap::new();
+                        map.insert("value".into(), JsonValue::String(w.value.clone()));
+                        map.insert("hint".into(), hint_to_json(w.hint));
+                        JsonValue::Object(map)
+                    })
+                    .collect(),
+            ),
+        );
+
+        // only present for parts verified locally, see `Answers::store_expected`.
+        if let Some(expected) = &value.expected {
+            map.insert("expected".into(), JsonValue::String(expected.clone()));
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Answer {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected answer to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected answer.day to be a Day struct.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|part| *part as u8)
+            .filter(|part| *part == 1 || *part == 2)
+            .ok_or("Expected answer.part to be 1 or 2.")?;
+
+        let correct = json
+            .get("correct")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected answer.correct to be null or string.")?;
+
+        let wrong = json
+            .get("wrong")
+            .and_then(|v| v.get::<Vec<JsonValue>>())
+            .ok_or("Expected answer.wrong to be an array.")?
+            .iter()
+            .map(|w| {
+                let w = w
+                    .get::<HashMap<String, JsonValue>>()
+                    .ok_or("Expected wrong answer to be a JSON object.")?;
+
+                let value = w
+                    .get("value")
+                    .and_then(|v| v.get::<String>())
+                    .ok_or("Expected wrong answer value to be a string.")?;
+
+                let hint = hint_from_json(w.get("hint").unwrap_or(&JsonValue::Null))?;
+
+                Ok(WrongAnswer {
+                    value: value.clone(),
+                    hint,
+                })
+            })
+            .collect::<Result<_, String>>()?;
+
+        let expected = match json.get("expected") {
+            None | Some(JsonValue::Null) => None,
+            Some(v) => Some(
+                v.get::<String>()
+                    .ok_or("Expected answer.expected to be null or string.")?
+                    .clone(),
+            ),
+        };
+
+        Ok(Answer {
+            day,
+            part,
+            correct: correct.cloned(),
+            wrong,
+            expected,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use crate::day;
+    use crate::template::aoc_client::{Hint, Submission};
+
+    use super::{Answers, Check};
+
+    fn get_mock_answers() -> Answers {
+        let mut answers = Answers::default();
+        answers.record(day!(1), 1, "42", &Submission::Correct);
+        answers.record(
+            day!(1),
+            2,
+            "100",
+            &Submission::Incorrect(Some(Hint::TooHigh)),
+        );
+        answers.record(day!(1), 2, "10", &Submission::Incorrect(Some(Hint::TooLow)));
+        answers.record(day!(1), 2, "abc", &Submission::Incorrect(None));
+        answers
+    }
+
+    #[test]
+    fn checks_accepted_answers() {
+        let answers = get_mock_answers();
+        assert_eq!(answers.check(day!(1), 1, "42"), Check::Accepted);
+        assert_eq!(answers.check(day!(1), 1, "43"), Check::Solved("42".into()));
+    }
+
+    #[test]
+    fn checks_rejected_answers() {
+        let answers = get_mock_answers();
+        assert_eq!(
+            answers.check(day!(1), 2, "100"),
+            Check::Rejected(Some(Hint::TooHigh))
+        );
+        assert_eq!(answers.check(day!(1), 2, "abc"), Check::Rejected(None));
+    }
+
+    #[test]
+    fn checks_bounds() {
+        let answers = get_mock_answers();
+        assert_eq!(
+            answers.check(day!(1), 2, "150"),
+            Check::OutOfBounds(Hint::TooHigh, "100".into())
+        );
+        assert_eq!(
+            answers.check(day!(1), 2, "5"),
+            Check::OutOfBounds(Hint::TooLow, "10".into())
+        );
+        assert_eq!(answers.check(day!(1), 2, "50"), Check::Unknown);
+        assert_eq!(answers.check(day!(2), 1, "50"), Check::Unknown);
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let answers = get_mock_answers();
+        let json = tinyjson::JsonValue::from(answers).stringify().unwrap();
+        let parsed = Answers::try_from(json).unwrap();
+        assert_eq!(parsed.data.len(), 2);
+        assert_eq!(parsed.check(day!(1), 1, "42"), Check::Accepted);
+        assert_eq!(parsed.get(day!(1), 2).unwrap().wrong.len(), 3);
+    }
+
+    #[test]
+    fn keeps_expected_answers_apart_from_accepted_ones() {
+        let mut answers = get_mock_answers();
+        answers.store_expected(day!(2), 1, "7");
+
+        assert_eq!(answers.check(day!(2), 1, "8"), Check::Unknown);
+        assert_eq!(answers.get(day!(2), 1).unwrap().correct, None);
+        assert_eq!(answers.expected(day!(2), 1), Some(&"7".into()));
+        assert_eq!(answers.expected(day!(1), 1), Some(&"42".into()));
+
+        let json = tinyjson::JsonValue::from(answers).stringify().unwrap();
+        let parsed = Answers::try_from(json).unwrap();
+        assert_eq!(parsed.expected(day!(2), 1), Some(&"7".into()));
+    }
+}
@@ -0,0 +1,528 @@
+/// Minimal native client for the Advent of Code website.
+///
+/// Authentication uses the `session` cookie of a logged in browser session. It is read from the
+/// `AOC_SESSION` env variable or, if not set, from a session file (see [`session_file_paths`]).
+/// Requests go to `https://adventofcode.com` unless `client.base_url` in `aoc.toml` or
+/// `AOC_BASE_URL` points somewhere else, e.g. a local mock server in CI.
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::template::{config, Day, Puzzle, Year};
+
+const USER_AGENT: &str = concat!(
+    "advent_of_code/",
+    env!("CARGO_PKG_VERSION"),
+    " (github.com/tgardner/adventofcode-2024)"
+);
+
+#[derive(Debug)]
+pub enum AocCommandError {
+    /// No session cookie was found in the environment or a session file.
+    SessionNotFound,
+    /// The server rejected the session cookie.
+    Unauthorized,
+    /// The puzzle for the requested day has not been unlocked yet.
+    PuzzleLocked,
+    /// An answer was submitted too recently. Contains the remaining wait time if known.
+    RateLimited(Option<String>),
+    /// The server answered with an unexpected HTTP status code.
+    HttpStatus(u16),
+    /// The server answered with a response that could not be interpreted.
+    UnexpectedResponse(String),
+    /// The request did not reach the server or the connection failed.
+    Transport(String),
+    IO(io::Error),
+}
+
+impl Display for AocCommandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocCommandError::SessionNotFound => write!(
+                f,
+                "no session cookie found. Set `AOC_SESSION` or create a `.adventofcode.session` file in your home directory."
+            ),
+            AocCommandError::Unauthorized => {
+                write!(f, "the session cookie was rejected. It might have expired.")
+            }
+            AocCommandError::PuzzleLocked => write!(f, "the puzzle has not been unlocked yet."),
+            AocCommandError::RateLimited(Some(wait)) => {
+                write!(f, "an answer was submitted too recently. {wait} left to wait.")
+            }
+            AocCommandError::RateLimited(None) => {
+                write!(f, "an answer was submitted too recently.")
+            }
+            AocCommandError::HttpStatus(status) => {
+                write!(f, "the server responded with HTTP status {status}.")
+            }
+            AocCommandError::UnexpectedResponse(body) => {
+                write!(f, "unexpected response from server: {body}")
+            }
+            AocCommandError::Transport(e) => write!(f, "request failed: {e}"),
+            AocCommandError::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for AocCommandError {}
+
+impl From<io::Error> for AocCommandError {
+    fn from(e: io::Error) -> Self {
+        AocCommandError::IO(e)
+    }
+}
+
+/// Hint given by the server when a wrong answer is submitted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// Outcome of an answer submission.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Submission {
+    Correct,
+    Incorrect(Option<Hint>),
+    /// The part was already solved, or is not unlocked yet.
+    WrongLevel,
+}
+
+impl Display for Submission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Submission::Correct => write!(f, "That's the right answer!"),
+            Submission::Incorrect(Some(Hint::TooHigh)) => {
+                write!(f, "That's not the right answer; your answer is too high.")
+            }
+            Submission::Incorrect(Some(Hint::TooLow)) => {
+                write!(f, "That's not the right answer; your answer is too low.")
+            }
+            Submission::Incorrect(None) => write!(f, "That's not the right answer."),
+            Submission::WrongLevel => write!(
+                f,
+                "You don't seem to be solving the right level. Did you already complete it?"
+            ),
+        }
+    }
+}
+
+pub struct AocClient {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    year: Year,
+}
+
+impl AocClient {
+    /// Creates a client for the event of a year from the session cookie found in the environment
+    /// and the client settings of the config.
+    pub fn from_env(year: Year) -> Result<Self, AocCommandError> {
+        let session = read_session().ok_or(AocCommandError::SessionNotFound)?;
+        let config = config::get();
+        Ok(Self::new(&config.base_url, &session, year, config.timeout))
+    }
+
+    pub fn new(base_url: &str, session: &str, year: Year, timeout: Duration) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(timeout)
+            .redirects(0)
+            .build();
+
+        Self {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            year,
+        }
+    }
+
+    /// Fetches the personal puzzle input for a day.
+    pub fn input(&self, day: Day) -> Result<String, AocCommandError> {
+        self.get(&format!("day/{}/input", day.into_inner()))
+    }
+
+    /// Fetches the puzzle description for a day, converted to markdown.
+    pub fn puzzle(&self, day: Day) -> Result<String, AocCommandError> {
+        let html = self.get(&format!("day/{}", day.into_inner()))?;
+        let articles = extract_articles(&html);
+
+        if articles.is_empty() {
+            return Err(AocCommandError::UnexpectedResponse(
+                "puzzle page does not contain a description.".into(),
+            ));
+        }
+
+        Ok(articles
+            .iter()
+            .map(|article| html_to_markdown(article))
+            .collect::<Vec<_>>()
+            .join("\n\n"))
+    }
+
+    /// Submits an answer for one part of a day.
+    pub fn submit(&self, day: Day, part: u8, answer: &str) -> Result<Submission, AocCommandError> {
+        let url = self.url(&format!("day/{}/answer", day.into_inner()));
+        let level = part.to_string();
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &level), ("answer", answer)]);
+
+        let body = into_body(response)?;
+        let message = extract_articles(&body)
+            .first()
+            .map(|article| html_to_markdown(article))
+            .unwrap_or(body);
+
+        parse_submission(&message)
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}/{}/{path}", self.base_url, self.year)
+    }
+
+    fn get(&self, path: &str) -> Result<String, AocCommandError> {
+        let response = self
+            .agent
+            .get(&self.url(path))
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+
+        into_body(response)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Fetches the puzzle description for a day, stores it and prints it.
+pub fn read(puzzle: Puzzle) -> Result<String, AocCommandError> {
+    let client = AocClient::from_env(puzzle.year)?;
+    let description = client.puzzle(puzzle.day)?;
+    write_file(&puzzle.puzzle_path(), &description)?;
+    println!("{description}");
+    Ok(description)
+}
+
+/// Fetches input and puzzle description for a day and stores them in the `data/<year>` folder.
+pub fn download(puzzle: Puzzle) -> Result<(), AocCommandError> {
+    let client = AocClient::from_env(puzzle.year)?;
+    let input_path = puzzle.input_path();
+    let puzzle_path = puzzle.puzzle_path();
+
+    let input = client.input(puzzle.day)?;
+    let description = client.puzzle(puzzle.day)?;
+
+    write_file(&input_path, &input)?;
+    write_file(&puzzle_path, &description)?;
+
+    println!("---");
+    println!(
+        "🎄 Successfully wrote input to \"{}\".",
+        input_path.display()
+    );
+    println!(
+        "🎄 Successfully wrote puzzle to \"{}\".",
+        puzzle_path.display()
+    );
+    Ok(())
+}
+
+pub fn submit(puzzle: Puzzle, part: u8, result: &str) -> Result<Submission, AocCommandError> {
+    let client = AocClient::from_env(puzzle.year)?;
+    client.submit(puzzle.day, part, result)
+}
+
+fn write_file(path: &Path, contents: &str) -> Result<(), io::Error> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)
+}
+
+/// Candidate locations of the session file, in order of precedence.
+///  1. `client.session_file` in `aoc.toml` or the path in `AOC_SESSION_FILE`, if set.
+///  2. `~/.adventofcode.session`
+///  3. `$XDG_CONFIG_HOME/adventofcode.session` or `~/.config/adventofcode.session`
+fn session_file_paths() -> Vec<PathBuf> {
+    let mut paths = vec![];
+
+    if let Some(path) = &config::get().session_file {
+        paths.push(path.clone());
+    }
+
+    let home = env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(PathBuf::from);
+
+    if let Some(home) = &home {
+        paths.push(home.join(".adventofcode.session"));
+    }
+
+    match env::var_os("XDG_CONFIG_HOME") {
+        Some(config) => paths.push(PathBuf::from(config).join("adventofcode.session")),
+        None => {
+            if let Some(home) = &home {
+                paths.push(home.join(".config").join("adventofcode.session"));
+            }
+        }
+    }
+
+    paths
+}
+
+fn read_session() -> Option<String> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        if !session.trim().is_empty() {
+            return Some(session);
+        }
+    }
+
+    session_file_paths()
+        .iter()
+        .filter_map(|path| fs::read_to_string(path).ok())
+        .map(|session| session.trim().to_string())
+        .find(|session| !session.is_empty())
+}
+
+fn into_body(response: Result<ureq::Response, ureq::Error>) -> Result<String, AocCommandError> {
+    match response {
+        Ok(response) => {
+            // the site redirects to the login page when the session is invalid.
+            if (300..400).contains(&response.status()) {
+                return Err(AocCommandError::Unauthorized);
+            }
+            response.into_string().map_err(AocCommandError::IO)
+        }
+        Err(ureq::Error::Status(status, response)) => {
+            let body = response.into_string().unwrap_or_default();
+            Err(status_error(status, &body))
+        }
+        Err(ureq::Error::Transport(e)) => Err(AocCommandError::Transport(e.to_string())),
+    }
+}
+
+fn status_error(status: u16, body: &str) -> AocCommandError {
+    match status {
+        401 | 403 => AocCommandError::Unauthorized,
+        400 | 500 if body.contains("log in") => AocCommandError::Unauthorized,
+        404 => AocCommandError::PuzzleLocked,
+        429 => AocCommandError::RateLimited(None),
+        status => AocCommandError::HttpStatus(status),
+    }
+}
+
+fn parse_submission(message: &str) -> Result<Submission, AocCommandError> {
+    if message.contains("That's the right answer") {
+        Ok(Submission::Correct)
+    } else if message.contains("That's not the right answer") {
+        let hint = if message.contains("too high") {
+            Some(Hint::TooHigh)
+        } else if message.contains("too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        };
+        Ok(Submission::Incorrect(hint))
+    } else if message.contains("You gave an answer too recently") {
+        let wait = message
+            .split("You have ")
+            .nth(1)
+            .and_then(|s| s.split(" left to wait").next())
+            .map(str::to_string);
+        Err(AocCommandError::RateLimited(wait))
+    } else if message.contains("You don't seem to be solving the right level") {
+        Ok(Submission::WrongLevel)
+    } else if message.contains("identify yourself") || message.contains("log in") {
+        Err(AocCommandError::Unauthorized)
+    } else {
+        Err(AocCommandError::UnexpectedResponse(message.trim().into()))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Returns the inner HTML of every `<article>` element in a page.
+fn extract_articles(html: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(open_end) = rest[start..].find('>') else {
+            break;
+        };
+        let content_start = start + open_end + 1;
+        let Some(len) = rest[content_start..].find("</article>") else {
+            break;
+        };
+        articles.push(&rest[content_start..content_start + len]);
+        rest = &rest[content_start + len..];
+    }
+
+    articles
+}
+
+/// Converts the subset of HTML used in puzzle descriptions to markdown.
+fn html_to_markdown(html: &str) -> String {
+    let mut out = String::new();
+    let mut rest = html;
+    let mut in_pre = false;
+    let mut links: Vec<Option<String>> = vec![];
+
+    while let Some(start) = rest.find('<') {
+        out.push_str(&decode_entities(&rest[..start]));
+
+        let Some(len) = rest[start..].find('>') else {
+            rest = &rest[start..];
+            break;
+        };
+
+        let tag = &rest[start + 1..start + len];
+        rest = &rest[start + len + 1..];
+
+        let closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        match (name.as_str(), closing) {
+            ("h2", false) => out.push_str("## "),
+            ("h2" | "p" | "ul", true) => out.push_str("\n\n"),
+            ("pre", false) => {
+                in_pre = true;
+                out.push_str("```\n");
+            }
+            ("pre", true) => {
+                in_pre = false;
+                if !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push_str("```\n\n");
+            }
+            ("code", _) if !in_pre => out.push('`'),
+            ("em", _) if !in_pre => out.push('*'),
+            ("li", false) => out.push_str("- "),
+            ("li", true) => out.push('\n'),
+            ("br", _) => out.push('\n'),
+            ("a", false) => {
+                let href = attribute(tag, "href").map(|href| decode_entities(&href));
+                if href.is_some() {
+                    out.push('[');
+                }
+                links.push(href);
+            }
+            ("a", true) => {
+                if let Some(Some(href)) = links.pop() {
+                    out.push_str(&format!("]({href})"));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    out.push_str(&decode_entities(rest));
+
+    // collapse runs of blank lines introduced by whitespace between block elements.
+    let mut markdown = String::with_capacity(out.len());
+    let mut newlines = 0;
+    for c in out.trim().chars() {
+        if c == '\n' {
+            newlines += 1;
+            if newlines > 2 {
+                continue;
+            }
+        } else {
+            newlines = 0;
+        }
+        markdown.push(c);
+    }
+    markdown.push('\n');
+    markdown
+}
+
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let pattern = format!("{name}=\"");
+    let start = tag.find(&pattern)? + pattern.len();
+    let len = tag[start..].find('"')?;
+    Some(tag[start..start + len].to_string())
+}
+
+fn decode_entities(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{extract_articles, html_to_markdown, parse_submission, Hint, Submission};
+
+    #[test]
+    fn converts_puzzle_html() {
+        let html = r#"<main><article class="day-desc"><h2>--- Day 1: Test ---</h2>
+<p>Some <em>text</em> with <code>code</code> and a <a href="/2024/about">link</a>.</p>
+<pre><code>1 &lt; 2
+3 &amp; 4
+</code></pre>
+<ul><li>one</li><li>two</li></ul>
+</article></main>"#;
+        let articles = extract_articles(html);
+        assert_eq!(articles.len(), 1);
+        assert_eq!(
+            html_to_markdown(articles[0]),
+            [
+                "## --- Day 1: Test ---",
+                "",
+                "Some *text* with `code` and a [link](/2024/about).",
+                "",
+                "```",
+                "1 < 2",
+                "3 & 4",
+                "```",
+                "",
+                "- one",
+                "- two",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn parses_submission_responses() {
+        assert_eq!(
+            parse_submission("That's the right answer! You are one gold star closer.").unwrap(),
+            Submission::Correct
+        );
+        assert_eq!(
+            parse_submission("That's not the right answer; your answer is too low.").unwrap(),
+            Submission::Incorrect(Some(Hint::TooLow))
+        );
+        assert_eq!(
+            parse_submission("You don't seem to be solving the right level.").unwrap(),
+            Submission::WrongLevel
+        );
+    }
+
+    #[test]
+    fn parses_rate_limit_responses() {
+        let err = parse_submission("You gave an answer too recently. You have 34s left to wait.")
+            .unwrap_err();
+        assert!(matches!(err, super::AocCommandError::RateLimited(Some(wait)) if wait == "34s"));
+    }
+}
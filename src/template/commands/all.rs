@@ -1,5 +1,30 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::time::Duration;
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use crate::template::runner::RunOptions;
+use crate::template::{all_days, run_multi::run_multi, Error, Format, Mode, Year};
+
+pub fn handle(
+    year: Year,
+    mode: Mode,
+    is_release: bool,
+    format: Format,
+    jobs: usize,
+    timeout: Option<Duration>,
+) -> Result<(), Error> {
+    let options = RunOptions {
+        format,
+        ..RunOptions::default()
+    };
+
+    run_multi(
+        year,
+        &all_days(year).collect(),
+        mode,
+        is_release,
+        &options,
+        jobs,
+        timeout,
+    );
+
+    Ok(())
 }
@@ -0,0 +1,29 @@
+use crate::template::{config, Error};
+use crate::template::{ANSI_ITALIC, ANSI_RESET};
+
+/// Print every setting with its resolved value and where it comes from.
+pub fn handle() -> Result<(), Error> {
+    let config = config::get();
+
+    match &config.file {
+        Some(path) => println!("Settings of \"{}\":", path.display()),
+        None => println!("No config file found, using defaults and environment variables:"),
+    }
+    println!();
+
+    let width = config
+        .settings()
+        .iter()
+        .map(|setting| setting.key.len())
+        .max()
+        .unwrap_or_default();
+
+    for setting in config.settings() {
+        println!(
+            "{:width$} = {}  {ANSI_ITALIC}({}){ANSI_RESET}",
+            setting.key, setting.value, setting.source
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use std::fs;
+
+use crate::template::crypt::{self, Key};
+use crate::template::{Day, Error, Year};
+
+/// Decrypt the encrypted inputs and puzzle descriptions of a year, or of a single day.
+/// Files that already have contents are only replaced with `overwrite`.
+pub fn handle(year: Year, day: Option<Day>, overwrite: bool) -> Result<(), Error> {
+    let key = Key::from_env()
+        .ok_or_else(|| Error::Config(format!("no key found, {}", crypt::missing_key())))?;
+
+    let mut count = 0;
+
+    for encrypted_path in crypt::files(year, true) {
+        let Some(path) = crypt::plain_path(&encrypted_path) else {
+            continue;
+        };
+
+        if !crypt::is_of_day(&path, day) {
+            continue;
+        }
+
+        let has_contents = fs::read_to_string(&path).is_ok_and(|c| !c.trim().is_empty());
+        if has_contents && !overwrite {
+            println!(
+                "Skipped \"{}\", it already exists. Use `--overwrite` to replace it.",
+                path.display()
+            );
+            continue;
+        }
+
+        let contents = fs::read(&encrypted_path)
+            .map_err(|e| e.to_string())
+            .and_then(|data| key.decrypt(&data))
+            .map_err(|e| {
+                Error::Io(format!(
+                    "Failed to decrypt \"{}\": {e}",
+                    encrypted_path.display()
+                ))
+            })?;
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(Error::io("Failed to create folder"))?;
+        }
+        fs::write(&path, contents).map_err(Error::io(&format!(
+            "Failed to write \"{}\"",
+            path.display()
+        )))?;
+
+        println!("Decrypted \"{}\".", path.display());
+        count += 1;
+    }
+
+    println!("---");
+    println!("🎄 Decrypted {count} file(s) of {year}.");
+    Ok(())
+}
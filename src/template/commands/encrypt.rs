@@ -0,0 +1,51 @@
+use std::fs;
+
+use crate::template::crypt::{self, Key};
+use crate::template::{Day, Error, Year};
+
+/// Encrypt the inputs and puzzle descriptions of a year, or of a single day, into their
+/// committable copies, e.g. `data/2024/inputs.enc/01.txt.enc`.
+pub fn handle(year: Year, day: Option<Day>) -> Result<(), Error> {
+    let key = Key::from_env()
+        .ok_or_else(|| Error::Config(format!("no key found, {}", crypt::missing_key())))?;
+
+    let mut count = 0;
+
+    for path in crypt::files(year, false) {
+        if !crypt::is_of_day(&path, day) {
+            continue;
+        }
+
+        let Some(encrypted_path) = crypt::encrypted_path(&path) else {
+            continue;
+        };
+
+        let contents = fs::read(&path)
+            .map_err(Error::io(&format!("Failed to read \"{}\"", path.display())))?;
+
+        // empty files are placeholders created by `scaffold`.
+        if contents.iter().all(u8::is_ascii_whitespace) {
+            continue;
+        }
+
+        let encrypted = key.encrypt(&contents);
+        if fs::read(&encrypted_path).is_ok_and(|existing| existing == encrypted) {
+            continue;
+        }
+
+        if let Some(parent) = encrypted_path.parent() {
+            fs::create_dir_all(parent).map_err(Error::io("Failed to create folder"))?;
+        }
+        fs::write(&encrypted_path, encrypted).map_err(Error::io(&format!(
+            "Failed to write \"{}\"",
+            encrypted_path.display()
+        )))?;
+
+        println!("Encrypted \"{}\".", path.display());
+        count += 1;
+    }
+
+    println!("---");
+    println!("🎄 Encrypted {count} changed file(s) of {year}.");
+    Ok(())
+}
@@ -0,0 +1,152 @@
+use std::{
+    fs,
+    io::{self, BufRead, IsTerminal, Write},
+    path::Path,
+};
+
+use crate::template::examples::{
+    code_blocks, find_answers, get_path, likely_example, CodeBlock, ExampleAnswers,
+};
+use crate::template::{crypt, Error, Puzzle};
+
+/// Number of lines of a code block shown when choosing an example.
+const PREVIEW_LINES: usize = 3;
+
+pub fn handle(puzzle: Puzzle, choose: bool, overwrite: bool) -> Result<(), Error> {
+    let puzzle_path = puzzle.puzzle_path();
+
+    let Ok(description) = crypt::read_to_string(&puzzle_path) else {
+        return Err(Error::Io(format!(
+            "Could not read puzzle description \"{}\". Run `cargo download {} --year {}` first.",
+            puzzle_path.display(),
+            puzzle.day,
+            puzzle.year
+        )));
+    };
+
+    let blocks = code_blocks(&description);
+
+    if blocks.is_empty() {
+        println!("🎄 No code blocks found in \"{}\".", puzzle_path.display());
+        return Ok(());
+    }
+
+    let Some(first) = select(&blocks, 1, choose) else {
+        println!(
+            "🎄 No example selected, \"{}\" was left as is.",
+            get_path(puzzle, 1).display()
+        );
+        return Ok(());
+    };
+
+    // part two usually reuses the example of part one, it gets a file of its own if it differs.
+    let second = select(&blocks, 2, choose)
+        .filter(|second| blocks[*second].content != blocks[first].content);
+
+    let mut written = vec![];
+    if write_example(puzzle, 1, &blocks[first], overwrite)? {
+        written.push(1);
+    }
+    if let Some(second) = second {
+        if write_example(puzzle, 2, &blocks[second], overwrite)? {
+            written.push(2);
+        }
+    }
+
+    // answers are only recorded for the examples that were written, a kept file might differ.
+    let answers: Vec<(u8, u8, String)> = find_answers(&description)
+        .into_iter()
+        .map(|(part, answer)| {
+            let example = if part == 2 && second.is_some() { 2 } else { 1 };
+            (example, part, answer)
+        })
+        .filter(|(example, ..)| written.contains(example))
+        .collect();
+
+    if answers.is_empty() {
+        return Ok(());
+    }
+
+    let mut example_answers = ExampleAnswers::read_from_file(puzzle.year)?;
+
+    for (example, part, answer) in answers {
+        example_answers.set(puzzle.day, example, part, &answer);
+        println!("🎄 Recorded `{answer}` as the answer to part {part} of example {example}.");
+    }
+
+    example_answers
+        .store_file(puzzle.year)
+        .map_err(Error::io("Failed to store example answers"))
+}
+
+/// Pick the example of a part. Asks for a choice from a numbered list if `choose` is set, or if
+/// no block looks like the example and the command runs in a terminal.
+fn select(blocks: &[CodeBlock], part: u8, choose: bool) -> Option<usize> {
+    if !blocks.iter().any(|block| block.part == part) {
+        return None;
+    }
+
+    let likely = likely_example(blocks, part);
+
+    if (likely.is_some() && !choose) || !io::stdin().is_terminal() {
+        return likely;
+    }
+
+    for (i, block) in blocks.iter().enumerate() {
+        let lines: Vec<&str> = block.content.lines().collect();
+        println!("[{}] Part {}, {} line(s):", i + 1, block.part, lines.len());
+        for line in lines.iter().take(PREVIEW_LINES) {
+            println!("    {line}");
+        }
+        if lines.len() > PREVIEW_LINES {
+            println!("    ...");
+        }
+    }
+
+    let default = likely.map(|i| format!(", default {}", i + 1));
+    print!(
+        "Choose the example of part {part} (0 to skip{}): ",
+        default.unwrap_or_default()
+    );
+    let _ = io::stdout().flush();
+
+    let mut choice = String::new();
+    io::stdin().lock().read_line(&mut choice).ok()?;
+
+    match choice.trim() {
+        "" => likely,
+        choice => choice
+            .parse::<usize>()
+            .ok()
+            .and_then(|n| n.checked_sub(1))
+            .filter(|i| *i < blocks.len()),
+    }
+}
+
+/// Write an example file. Files that already have contents are only replaced with `overwrite`.
+/// Returns whether the file was written.
+fn write_example(
+    puzzle: Puzzle,
+    example: u8,
+    block: &CodeBlock,
+    overwrite: bool,
+) -> Result<bool, Error> {
+    let path = get_path(puzzle, example);
+    let has_contents = fs::read_to_string(&path).is_ok_and(|contents| !contents.trim().is_empty());
+
+    if has_contents && !overwrite {
+        println!(
+            "🎄 \"{}\" already exists, use `--overwrite` to replace it.",
+            path.display()
+        );
+        return Ok(false);
+    }
+
+    if let Some(parent) = Path::new(&path).parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+
+    fs::write(&path, &block.content).map_err(Error::io("Failed to write example file"))?;
+    println!("🎄 Wrote example {example} to \"{}\".", path.display());
+    Ok(true)
+}
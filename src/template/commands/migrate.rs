@@ -0,0 +1,161 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::template::readme_benchmarks::marker;
+use crate::template::{all_days, config, Error, Puzzle, Year};
+
+/// Folders of `data` that are namespaced by year.
+const DATA_DIRS: [&str; 3] = ["inputs", "examples", "puzzles"];
+
+/// Files of `data` that are namespaced by year.
+const DATA_FILES: [&str; 2] = ["answers.json", "timings.json"];
+
+/// Move a repository from the flat layout (`src/bin/01.rs`, `data/inputs/01.txt`) to the layout
+/// namespaced by year (`src/bin/2024-01.rs`, `data/2024/inputs/01.txt`).
+/// Existing destinations are never replaced, so the command can be run again after a failure.
+pub fn handle(year: Year) -> Result<(), Error> {
+    let data_dir = PathBuf::from("./data");
+
+    for dir in DATA_DIRS {
+        let Ok(entries) = fs::read_dir(data_dir.join(dir)) else {
+            continue;
+        };
+
+        for entry in entries.filter_map(Result::ok) {
+            let destination = year.data_dir().join(dir).join(entry.file_name());
+            move_file(&entry.path(), &destination, |contents| contents)?;
+        }
+
+        // only removed if every file was moved.
+        let _ = fs::remove_dir(data_dir.join(dir));
+    }
+
+    for file in DATA_FILES {
+        move_file(&data_dir.join(file), &year.data_dir().join(file), |c| c)?;
+    }
+
+    for day in all_days(year) {
+        let module_path = PathBuf::from(format!("./src/bin/{day}.rs"));
+        let puzzle = Puzzle::new(year, day);
+
+        move_file(&module_path, Path::new(&puzzle.bin_path()), |module| {
+            migrate_module(&module, puzzle)
+        })?;
+    }
+
+    let readme_path = &config::get().readme;
+    match fs::read_to_string(readme_path) {
+        Ok(readme) => {
+            let migrated = migrate_readme(&readme, year);
+            if migrated != readme {
+                fs::write(readme_path, migrated).map_err(Error::io(&format!(
+                    "Failed to update \"{}\"",
+                    readme_path.display()
+                )))?;
+                println!("Updated benchmarks of \"{}\".", readme_path.display());
+            }
+        }
+        Err(e) => eprintln!("Skipped \"{}\": {e}", readme_path.display()),
+    }
+
+    println!("---");
+    println!("🎄 Migrated the puzzles of {year}.");
+    Ok(())
+}
+
+/// Move a file, rewriting its contents on the way if it is text.
+fn move_file(from: &Path, to: &Path, migrate: impl FnOnce(String) -> String) -> Result<(), Error> {
+    if !from.is_file() {
+        return Ok(());
+    }
+
+    if to.exists() {
+        println!(
+            "Skipped \"{}\", \"{}\" already exists.",
+            from.display(),
+            to.display()
+        );
+        return Ok(());
+    }
+
+    let result = to
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|()| match fs::read_to_string(from) {
+            Ok(contents) => {
+                fs::write(to, migrate(contents))?;
+                fs::remove_file(from)
+            }
+            // files that aren't text, e.g. `.keep`, are moved as is.
+            Err(_) => fs::rename(from, to),
+        });
+
+    result.map_err(Error::io(&format!("Failed to move \"{}\"", from.display())))?;
+    println!("Moved \"{}\" to \"{}\".", from.display(), to.display());
+    Ok(())
+}
+
+/// Rewrite the `solution!` invocation and the `read_file` calls of a module for the new layout.
+fn migrate_module(module: &str, puzzle: Puzzle) -> String {
+    let day = puzzle.day.into_inner();
+
+    module
+        .replace(
+            &format!("solution!({day}"),
+            &format!("solution!({}, day = {day}", puzzle.year),
+        )
+        .replace(", DAY)", ", PUZZLE)")
+        .replace(", DAY, ", ", PUZZLE, ")
+}
+
+/// Namespace the benchmarks marker and the links to the modules of the README by year.
+fn migrate_readme(readme: &str, year: Year) -> String {
+    let mut readme = readme.replace(
+        &format!("<!--- {} --->", config::get().benchmarks_marker),
+        &marker(year),
+    );
+
+    for day in all_days(year) {
+        readme = readme.replace(
+            &format!("](./src/bin/{day}.rs)"),
+            &format!("]({})", Puzzle::new(year, day).bin_path()),
+        );
+    }
+
+    readme
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{migrate_module, migrate_readme};
+    use crate::template::Puzzle;
+    use crate::{day, year};
+
+    #[test]
+    fn migrates_modules() {
+        let module = "advent_of_code::solution!(7, parse = parse);\n\
+                      let input = read_file(\"examples\", DAY);\n\
+                      let input = read_file_part(\"examples\", DAY, 2);";
+
+        assert_eq!(
+            migrate_module(module, Puzzle::new(year!(2023), day!(7))),
+            "advent_of_code::solution!(2023, day = 7, parse = parse);\n\
+             let input = read_file(\"examples\", PUZZLE);\n\
+             let input = read_file_part(\"examples\", PUZZLE, 2);"
+        );
+    }
+
+    #[test]
+    fn migrates_readme() {
+        let readme = "<!--- benchmarking table --->\n| [Day 1](./src/bin/01.rs) | `1.0ms` | - |";
+
+        assert_eq!(
+            migrate_readme(readme, year!(2023)),
+            "<!--- benchmarking table 2023 --->\n| [Day 1](./src/bin/2023-01.rs) | `1.0ms` | - |"
+        );
+    }
+}
@@ -1,6 +1,13 @@
 pub mod all;
+pub mod config;
+pub mod decrypt;
 pub mod download;
+pub mod encrypt;
+pub mod examples;
+pub mod migrate;
 pub mod read;
 pub mod scaffold;
 pub mod solve;
+pub mod status;
 pub mod time;
+pub mod verify;
@@ -1,15 +1,21 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
-    process,
+    path::Path,
 };
 
-use crate::template::Day;
+use crate::template::crypt;
+use crate::template::examples;
+use crate::template::templates::{self, Placeholders};
+use crate::template::{Error, Puzzle};
 
 const MODULE_TEMPLATE: &str =
     include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
 
-fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
+/// Type of the answers if no other is given.
+pub const DEFAULT_RETURN_TYPE: &str = "u32";
+
+fn safe_create_file(path: &Path, overwrite: bool) -> Result<File, std::io::Error> {
     let mut file = OpenOptions::new();
     if overwrite {
         file.create(true);
@@ -19,7 +25,10 @@ fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error>
     file.truncate(true).write(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
+fn create_file(path: &Path) -> Result<File, std::io::Error> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
     OpenOptions::new()
         .write(true)
         .create(true)
@@ -27,53 +36,77 @@ fn create_file(path: &str) -> Result<File, std::io::Error> {
         .open(path)
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+/// Whether a file exists and is not empty, e.g. an input that was downloaded before scaffolding.
+fn has_contents(path: &Path) -> bool {
+    fs::read_to_string(path).is_ok_and(|contents| !contents.trim().is_empty())
+}
+
+pub fn handle(
+    puzzle: Puzzle,
+    overwrite: bool,
+    template: Option<&str>,
+    return_type: &str,
+) -> Result<(), Error> {
+    let input_path = puzzle.input_path();
+    let example_path = examples::get_path(puzzle, 1);
+    let module_path = puzzle.bin_path();
+    let puzzle_path = puzzle.puzzle_path();
 
-    let mut file = match safe_create_file(&module_path, overwrite) {
-        Ok(file) => file,
-        Err(e) => {
-            eprintln!("Failed to create module file: {e}");
-            process::exit(1);
-        }
+    let template = match template {
+        None => MODULE_TEMPLATE.to_string(),
+        Some(name) => match templates::read(name) {
+            Ok(template) => template,
+            Err(e) => {
+                let mut message = format!(
+                    "Failed to read template \"{}\": {e}",
+                    templates::get_path(name).display()
+                );
+                let available = templates::available();
+                if !available.is_empty() {
+                    message += &format!("\nAvailable templates: {}", available.join(", "));
+                }
+                return Err(Error::Io(message));
+            }
+        },
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
-        Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {e}");
-            process::exit(1);
-        }
-    }
+    let placeholders = Placeholders {
+        year: puzzle.year,
+        day: puzzle.day,
+        title: crypt::read_to_string(&puzzle_path)
+            .ok()
+            .and_then(|description| templates::parse_title(&description)),
+        return_type: return_type.to_string(),
+    };
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
-            process::exit(1);
-        }
+    let mut file = safe_create_file(Path::new(&module_path), overwrite)
+        .map_err(Error::io("Failed to create module file"))?;
+
+    file.write_all(templates::render(&template, &placeholders).as_bytes())
+        .map_err(Error::io("Failed to write module contents"))?;
+    println!("Created module file \"{}\"", &module_path);
+
+    if has_contents(&input_path) {
+        println!("Kept existing input file \"{}\"", input_path.display());
+    } else if let Some(encrypted) = crypt::encrypted_path(&input_path).filter(|p| p.is_file()) {
+        println!("Kept encrypted input file \"{}\"", encrypted.display());
+    } else {
+        create_file(&input_path).map_err(Error::io("Failed to create input file"))?;
+        println!("Created empty input file \"{}\"", input_path.display());
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
-            process::exit(1);
-        }
+    if has_contents(&example_path) {
+        println!("Kept existing example file \"{}\"", example_path.display());
+    } else {
+        create_file(&example_path).map_err(Error::io("Failed to create example file"))?;
+        println!("Created empty example file \"{}\"", example_path.display());
     }
 
     println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    println!(
+        "🎄 Type `cargo solve {} --year {}` to run your solution.",
+        puzzle.day, puzzle.year
+    );
+
+    Ok(())
 }
@@ -1,9 +1,17 @@
 use std::process::{Command, Stdio};
 
-use crate::template::Day;
+use crate::template::runner::InputSource;
+use crate::template::{Error, Format, Puzzle};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+pub fn handle(
+    puzzle: Puzzle,
+    release: bool,
+    dhat: bool,
+    submit_part: Option<u8>,
+    format: Format,
+    input: &InputSource,
+) -> Result<(), Error> {
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), puzzle.to_string()];
 
     if dhat {
         cmd_args.extend([
@@ -23,12 +31,36 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         cmd_args.push(submit_part.to_string());
     }
 
-    let mut cmd = Command::new("cargo")
+    if format != Format::Text {
+        cmd_args.push("--format".to_string());
+        cmd_args.push(format.as_str().to_string());
+    }
+
+    // stdin is inherited, so `--stdin` only needs to be passed on.
+    match input {
+        InputSource::Default => {}
+        InputSource::File(path) => {
+            cmd_args.push("--input".to_string());
+            cmd_args.push(path.display().to_string());
+        }
+        InputSource::Stdin => cmd_args.push("--stdin".to_string()),
+        InputSource::Examples(example) => {
+            cmd_args.push("--example".to_string());
+            cmd_args.extend(example.map(|example| example.to_string()));
+        }
+    }
+
+    let status = Command::new("cargo")
         .args(&cmd_args)
         .stdout(Stdio::inherit())
         .stderr(Stdio::inherit())
-        .spawn()
-        .unwrap();
+        .status()
+        .map_err(Error::io("Failed to run cargo"))?;
 
-    cmd.wait().unwrap();
+    // the binary already printed its error.
+    if status.success() {
+        Ok(())
+    } else {
+        Err(Error::Exited(status.code()))
+    }
 }
@@ -0,0 +1,354 @@
+use std::{collections::HashMap, fs, panic, path::Path};
+use tinyjson::JsonValue;
+
+use crate::template::answers::Answers;
+use crate::template::examples::{self, ExampleAnswers};
+use crate::template::runner::{self, InputSource, RunOptions, Solution};
+use crate::template::timings::Timings;
+use crate::template::{all_days, Day, Error, Format, Puzzle, Year};
+use crate::template::{crypt, input};
+use crate::template::{ANSI_BOLD, ANSI_GREEN, ANSI_RED, ANSI_RESET, ANSI_YELLOW};
+
+/// State of the input of a day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Input {
+    Missing,
+    /// The file exists, but is empty or not the real input, see [`input::warning`].
+    Placeholder,
+    Plain,
+    /// Only the encrypted copy exists, see `cargo encrypt`.
+    Encrypted,
+}
+
+impl Input {
+    fn as_str(self) -> &'static str {
+        match self {
+            Input::Missing => "missing",
+            Input::Placeholder => "placeholder",
+            Input::Plain => "plain",
+            Input::Encrypted => "encrypted",
+        }
+    }
+}
+
+/// Progress of a single day.
+#[derive(Clone, Debug, PartialEq)]
+struct DayStatus {
+    day: Day,
+    has_bin: bool,
+    input: Input,
+    examples: usize,
+    /// Example parts that match their expected answer, and example parts with an expected answer.
+    tests: (usize, usize),
+    /// Total duration of the stored timings, if the day was benched.
+    nanos: Option<f64>,
+    /// Whether every part of the day was benched, see [`Timings::is_day_complete`].
+    is_timed: bool,
+    /// Parts with an accepted answer.
+    stars: usize,
+}
+
+/// Print the progress of every day of a year: whether it has a solution, an input, examples and
+/// passing example tests, stored timings and stars.
+pub fn handle(year: Year, solutions: &[Solution], json: bool) -> Result<(), Error> {
+    let answers = Answers::read_from_file(year)?;
+    let expected = ExampleAnswers::read_from_file(year)?;
+    let timings = Timings::read_from_file(year)?;
+
+    let days: Vec<DayStatus> = all_days(year)
+        .map(|day| {
+            let puzzle = Puzzle::new(year, day);
+            let solution = solutions.iter().find(|s| s.puzzle == puzzle);
+            let timing = timings.data.iter().find(|t| t.day == day);
+
+            DayStatus {
+                day,
+                has_bin: Path::new(&puzzle.bin_path()).is_file(),
+                input: find_input(puzzle),
+                examples: examples::find(puzzle).len(),
+                tests: solution.map_or((0, 0), |solution| check_examples(solution, &expected)),
+                nanos: timing.map(|t| t.total_nanos),
+                is_timed: timings.is_day_complete(year, day),
+                stars: [1, 2]
+                    .iter()
+                    .filter(|part| {
+                        answers
+                            .get(day, **part)
+                            .is_some_and(|answer| answer.correct.is_some())
+                    })
+                    .count(),
+            }
+        })
+        .collect();
+
+    if json {
+        println!("{}", to_json(&days).stringify().unwrap());
+    } else {
+        print_table(year, &days);
+    }
+
+    Ok(())
+}
+
+fn find_input(puzzle: Puzzle) -> Input {
+    let path = puzzle.input_path();
+
+    match fs::read_to_string(&path) {
+        Ok(contents) if input::warning(&input::normalize(&contents)).is_none() => Input::Plain,
+        _ if crypt::encrypted_path(&path).is_some_and(|path| path.is_file()) => Input::Encrypted,
+        Ok(_) => Input::Placeholder,
+        Err(_) => Input::Missing,
+    }
+}
+
+/// Run a solution against every example of its day and count the parts that match their expected answer.
+fn check_examples(solution: &Solution, expected: &ExampleAnswers) -> (usize, usize) {
+    let puzzle = solution.puzzle;
+    let (mut passed, mut total) = (0, 0);
+
+    for example in examples::find(puzzle) {
+        let parts: Vec<(u8, &String)> = [1, 2]
+            .into_iter()
+            .filter_map(|part| Some((part, expected.get(puzzle.day, example, part)?)))
+            .collect();
+
+        if parts.is_empty() {
+            continue;
+        }
+        total += parts.len();
+
+        let options = RunOptions {
+            format: Format::Json,
+            input: InputSource::Examples(Some(example)),
+            ..RunOptions::default()
+        };
+
+        let Ok(input) = options.input.read(puzzle) else {
+            continue;
+        };
+        if solution.check_input(&input).is_err() {
+            continue;
+        }
+
+        // the panic message is printed by the default hook.
+        let (result, _) =
+            runner::capture(|| panic::catch_unwind(|| (solution.solve)(&input, &options)));
+        let Ok(result) = result else {
+            continue;
+        };
+
+        passed += parts
+            .iter()
+            .filter(|(part, answer)| {
+                result
+                    .parts
+                    .iter()
+                    .any(|p| p.part == *part && p.answer.as_ref() == Some(answer))
+            })
+            .count();
+    }
+
+    (passed, total)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A table cell, coloured by whether it is done, partially done or missing.
+fn cell(text: &str, width: usize, color: &str) -> String {
+    format!("{color}{text:width$}{ANSI_RESET}")
+}
+
+fn print_table(year: Year, days: &[DayStatus]) {
+    println!(
+        "{ANSI_BOLD}| Day | Bin | Input       | Examples | Tests | Timings      | Stars |{ANSI_RESET}"
+    );
+
+    for status in days {
+        let bin = if status.has_bin {
+            cell("✓", 3, ANSI_GREEN)
+        } else {
+            cell("-", 3, ANSI_RED)
+        };
+
+        let input_color = match status.input {
+            Input::Plain | Input::Encrypted => ANSI_GREEN,
+            Input::Placeholder => ANSI_YELLOW,
+            Input::Missing => ANSI_RED,
+        };
+
+        let examples_color = if status.examples > 0 {
+            ANSI_GREEN
+        } else {
+            ANSI_RED
+        };
+
+        let tests = match status.tests {
+            (_, 0) => cell("-", 5, ANSI_YELLOW),
+            (passed, total) if passed == total => cell(&format!("{passed}/{total}"), 5, ANSI_GREEN),
+            (passed, total) => cell(&format!("{passed}/{total}"), 5, ANSI_RED),
+        };
+
+        let timings = match status.nanos {
+            Some(nanos) => cell(
+                &format!("{:.2}ms", nanos / 1_000_000_f64),
+                12,
+                if status.is_timed {
+                    ANSI_GREEN
+                } else {
+                    ANSI_YELLOW
+                },
+            ),
+            None => cell("-", 12, ANSI_RED),
+        };
+
+        let stars = match status.stars {
+            0 => cell("-", 5, ANSI_RED),
+            1 => cell("★", 5, ANSI_YELLOW),
+            _ => cell("★★", 5, ANSI_GREEN),
+        };
+
+        println!(
+            "| {}  | {bin} | {} | {} | {tests} | {timings} | {stars} |",
+            status.day,
+            cell(status.input.as_str(), 11, input_color),
+            cell(&status.examples.to_string(), 8, examples_color),
+        );
+    }
+
+    let totals = Totals::from(days);
+    println!("---");
+    println!(
+        "{ANSI_BOLD}Total{ANSI_RESET}: {}/{} bins, {} inputs, {} days with examples, {}/{} example parts passing, {} days timed, {} ★ of {year}.",
+        totals.bins,
+        days.len(),
+        totals.inputs,
+        totals.examples,
+        totals.tests_passed,
+        totals.tests,
+        totals.timed,
+        totals.stars,
+    );
+}
+
+/// Sums over all days of a year.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct Totals {
+    bins: usize,
+    inputs: usize,
+    examples: usize,
+    tests_passed: usize,
+    tests: usize,
+    timed: usize,
+    stars: usize,
+}
+
+impl From<&[DayStatus]> for Totals {
+    fn from(days: &[DayStatus]) -> Self {
+        let count = |f: fn(&DayStatus) -> bool| days.iter().filter(|status| f(status)).count();
+
+        Totals {
+            bins: count(|s| s.has_bin),
+            inputs: count(|s| matches!(s.input, Input::Plain | Input::Encrypted)),
+            examples: count(|s| s.examples > 0),
+            tests_passed: days.iter().map(|s| s.tests.0).sum(),
+            tests: days.iter().map(|s| s.tests.1).sum(),
+            timed: count(|s| s.is_timed),
+            stars: days.iter().map(|s| s.stars).sum(),
+        }
+    }
+}
+
+fn to_json(days: &[DayStatus]) -> JsonValue {
+    let number = |n: usize| JsonValue::Number(n as f64);
+
+    let days_json = days
+        .iter()
+        .map(|status| {
+            let mut map: HashMap<String, JsonValue> = HashMap::new();
+            map.insert("day".into(), JsonValue::String(status.day.to_string()));
+            map.insert("bin".into(), JsonValue::Boolean(status.has_bin));
+            map.insert(
+                "input".into(),
+                JsonValue::String(status.input.as_str().into()),
+            );
+            map.insert("examples".into(), number(status.examples));
+            map.insert("tests_passed".into(), number(status.tests.0));
+            map.insert("tests".into(), number(status.tests.1));
+            map.insert(
+                "total_nanos".into(),
+                status.nanos.map_or(JsonValue::Null, JsonValue::Number),
+            );
+            map.insert("timed".into(), JsonValue::Boolean(status.is_timed));
+            map.insert("stars".into(), number(status.stars));
+            JsonValue::Object(map)
+        })
+        .collect();
+
+    let totals = Totals::from(days);
+    let mut totals_json: HashMap<String, JsonValue> = HashMap::new();
+    totals_json.insert("bins".into(), number(totals.bins));
+    totals_json.insert("inputs".into(), number(totals.inputs));
+    totals_json.insert("examples".into(), number(totals.examples));
+    totals_json.insert("tests_passed".into(), number(totals.tests_passed));
+    totals_json.insert("tests".into(), number(totals.tests));
+    totals_json.insert("timed".into(), number(totals.timed));
+    totals_json.insert("stars".into(), number(totals.stars));
+
+    let mut map: HashMap<String, JsonValue> = HashMap::new();
+    map.insert("days".into(), JsonValue::Array(days_json));
+    map.insert("totals".into(), JsonValue::Object(totals_json));
+    JsonValue::Object(map)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{to_json, DayStatus, Input, Totals};
+    use crate::template::Day;
+
+    fn day(day: u8, input: Input, tests: (usize, usize), stars: usize) -> DayStatus {
+        DayStatus {
+            day: Day::new(day).unwrap(),
+            has_bin: true,
+            input,
+            examples: 1,
+            tests,
+            nanos: Some(1_500_000_f64),
+            is_timed: stars == 2,
+            stars,
+        }
+    }
+
+    #[test]
+    fn sums_up_days() {
+        let days = [
+            day(1, Input::Plain, (2, 2), 2),
+            day(2, Input::Encrypted, (1, 2), 1),
+            day(3, Input::Placeholder, (0, 0), 0),
+        ];
+
+        assert_eq!(
+            Totals::from(&days[..]),
+            Totals {
+                bins: 3,
+                inputs: 2,
+                examples: 3,
+                tests_passed: 3,
+                tests: 4,
+                timed: 1,
+                stars: 3,
+            }
+        );
+    }
+
+    #[test]
+    fn serializes_to_json() {
+        let json = to_json(&[day(1, Input::Encrypted, (1, 2), 1)]);
+
+        assert_eq!(json["days"][0]["day"], "01".to_string().into());
+        assert_eq!(json["days"][0]["input"], "encrypted".to_string().into());
+        assert_eq!(json["days"][0]["tests_passed"], 1.0.into());
+        assert_eq!(json["totals"]["stars"], 1.0.into());
+    }
+}
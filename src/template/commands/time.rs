@@ -1,40 +1,180 @@
 use std::collections::HashSet;
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-use crate::template::run_multi::run_multi;
-use crate::template::timings::Timings;
-use crate::template::{all_days, readme_benchmarks, Day};
+use crate::template::run_multi::{run_memory, run_multi};
+use crate::template::runner::RunOptions;
+use crate::template::timings::{Regression, Timings};
+use crate::template::{
+    all_days, readme_benchmarks, Day, Error, Format, Mode, Year, ANSI_BOLD, ANSI_RED, ANSI_RESET,
+};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
-    let stored_timings = Timings::read_from_file();
+/// Default slowdown, in percent, above which a step is reported as a regression.
+pub const DEFAULT_THRESHOLD: f64 = 10.0;
+
+/// Flags of the `time` command.
+pub struct TimeArgs {
+    pub run_all: bool,
+    pub store: bool,
+    pub budget: Duration,
+    pub format: Format,
+    /// Slowdown in percent compared to the best previous run.
+    pub threshold: f64,
+    pub fail_on_regression: bool,
+    /// Wall-clock time after which a day is stopped.
+    pub timeout: Option<Duration>,
+    /// Also measure the heap usage of every step with dhat.
+    pub memory: bool,
+}
+
+pub fn handle(year: Year, mode: Mode, day: Option<Day>, args: &TimeArgs) -> Result<(), Error> {
+    let stored_timings = Timings::read_from_file(year)?;
 
     let days_to_run = day.map_or_else(
         || {
-            if run_all {
-                all_days().collect()
+            if args.run_all {
+                all_days(year).collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
-                    .filter(|day| !stored_timings.is_day_complete(*day))
+                all_days(year)
+                    .filter(|day| !stored_timings.is_day_complete(year, *day))
                     .collect()
             }
         },
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let options = RunOptions {
+        is_timed: true,
+        budget: args.budget,
+        format: args.format,
+        ..RunOptions::default()
+    };
+
+    let mut timings = run_multi(year, &days_to_run, mode, true, &options, 1, args.timeout)
+        .timings
+        .unwrap();
+
+    if args.memory {
+        // the dhat allocator slows solutions down, so heap usage is measured in a separate, untimed run.
+        for (day, result) in run_memory(year, &days_to_run, args.timeout, args.format) {
+            let Some(timing) = timings.data.iter_mut().find(|t| t.day == day) else {
+                continue;
+            };
+
+            let part = |part| result.parts.iter().find(|r| r.part == part)?.memory;
+            timing.parse_memory = result.parse.as_ref().and_then(|r| r.memory);
+            timing.part_1_memory = part(1);
+            timing.part_2_memory = part(2);
+        }
+    }
+
+    timings.stamp(&now_utc(), git_commit().as_deref());
 
-    if store {
+    let regressions = stored_timings.regressions(&timings, args.threshold / 100.0);
+    print_regressions(&regressions, args);
+
+    if args.store {
         let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+        merged_timings
+            .store_file(year)
+            .map_err(Error::io("Failed to store timings"))?;
 
-        println!();
-        match readme_benchmarks::update(merged_timings) {
-            Ok(()) => {
-                println!("Stored updated benchmarks.");
-            }
-            Err(_) => {
-                eprintln!("Failed to store updated benchmarks.");
-            }
+        readme_benchmarks::update(year, merged_timings)
+            .map_err(Error::io("Failed to store updated benchmarks"))?;
+
+        // keep machine-readable output parseable.
+        if args.format == Format::Text {
+            println!();
+            println!("Stored updated benchmarks.");
         }
     }
+
+    if args.fail_on_regression && !regressions.is_empty() {
+        return Err(Error::Check(format!(
+            "{} step(s) regressed by more than {}%.",
+            regressions.len(),
+            args.threshold
+        )));
+    }
+
+    Ok(())
+}
+
+fn print_regressions(regressions: &[Regression], args: &TimeArgs) {
+    if regressions.is_empty() {
+        return;
+    }
+
+    let lines: Vec<String> = regressions
+        .iter()
+        .map(|r| {
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let duration = |nanos: f64| Duration::from_nanos(nanos.round() as u64);
+            let percent = (r.nanos / r.best_nanos - 1.0) * 100.0;
+
+            format!(
+                "Day {} {}: {:.1?}, best {:.1?} {ANSI_RED}(+{percent:.0}%){ANSI_RESET}",
+                r.day,
+                r.step,
+                duration(r.nanos),
+                duration(r.best_nanos),
+            )
+        })
+        .collect();
+
+    let header = format!(
+        "{ANSI_BOLD}Regressions (more than {}% slower than the best run):{ANSI_RESET}",
+        args.threshold
+    );
+
+    // keep machine-readable output parseable.
+    if args.format == Format::Text {
+        println!();
+        println!("{header}");
+        lines.iter().for_each(|line| println!("{line}"));
+    } else {
+        eprintln!("{header}");
+        lines.iter().for_each(|line| eprintln!("{line}"));
+    }
+}
+
+/// Current UTC time, formatted like `2024-12-20T08:15:00Z`.
+fn now_utc() -> String {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+
+    let (days, rem) = (secs / 86_400, secs % 86_400);
+
+    // civil date from days since the epoch, see: http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z % 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = doy - (153 * mp + 2) / 5 + 1;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+    let y = yoe + era * 400 + u64::from(m <= 2);
+
+    format!(
+        "{y:04}-{m:02}-{d:02}T{:02}:{:02}:{:02}Z",
+        rem / 3600,
+        rem % 3600 / 60,
+        rem % 60
+    )
+}
+
+/// Short hash of the checked out git commit, if any.
+fn git_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()?;
+
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
 }
@@ -0,0 +1,116 @@
+use std::collections::HashSet;
+use std::time::Duration;
+
+use crate::template::answers::Answers;
+use crate::template::run_multi::{run_multi, DayAnswers};
+use crate::template::runner::RunOptions;
+use crate::template::{
+    all_days, Day, Error, Mode, Year, ANSI_BOLD, ANSI_GREEN, ANSI_RED, ANSI_RESET, ANSI_YELLOW,
+};
+
+/// Result of comparing the output of one part against its recorded answer.
+enum Verdict {
+    Pass,
+    Fail(String, Option<String>),
+    Missing,
+}
+
+pub fn handle(
+    year: Year,
+    mode: Mode,
+    day: Option<Day>,
+    is_release: bool,
+    store: bool,
+    timeout: Option<Duration>,
+) -> Result<(), Error> {
+    let days_to_run = day.map_or_else(|| all_days(year).collect(), |day| HashSet::from([day]));
+    // read first, so a malformed ledger fails before every day has run.
+    let mut answers = Answers::read_from_file(year)?;
+
+    let run = run_multi(
+        year,
+        &days_to_run,
+        mode,
+        is_release,
+        &RunOptions::default(),
+        1,
+        timeout,
+    );
+
+    println!();
+    println!("{ANSI_BOLD}| Day | Part 1 | Part 2 |{ANSI_RESET}");
+
+    let mut failures = 0;
+    let mut stored = 0;
+
+    // NOTE: use non-duplicate, sorted day values.
+    for day in all_days(year).filter(|day| days_to_run.contains(day)) {
+        let output = run
+            .answers
+            .iter()
+            .find(|(d, _)| *d == day)
+            .map(|(_, output)| output.clone());
+
+        // skip days that neither ran nor have recorded answers.
+        if output.is_none() && [1, 2].iter().all(|part| answers.get(day, *part).is_none()) {
+            continue;
+        }
+
+        let output = output.unwrap_or_default();
+        let verdicts = [1, 2].map(|part| verify_part(&answers, day, part, &output));
+
+        let cells: Vec<String> = verdicts
+            .iter()
+            .map(|verdict| match verdict {
+                Verdict::Pass => format!("{ANSI_GREEN}pass{ANSI_RESET}"),
+                Verdict::Fail(expected, actual) => format!(
+                    "{ANSI_RED}fail{ANSI_RESET} (expected `{expected}`, got `{}`)",
+                    actual.as_deref().unwrap_or("✖")
+                ),
+                Verdict::Missing => format!("{ANSI_YELLOW}missing{ANSI_RESET}"),
+            })
+            .collect();
+
+        println!("| {day} | {} | {} |", cells[0], cells[1]);
+
+        for (part, verdict) in [1, 2].into_iter().zip(&verdicts) {
+            match verdict {
+                Verdict::Fail(..) => failures += 1,
+                Verdict::Missing if store => {
+                    if let Some(value) = output.get(part) {
+                        answers.store_expected(day, part, value);
+                        stored += 1;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    if stored > 0 {
+        answers
+            .store_file(year)
+            .map_err(Error::io("Failed to store answers"))?;
+        println!("\nStored {stored} new answer(s).");
+    }
+
+    if failures > 0 {
+        println!();
+        return Err(Error::Check(format!(
+            "{failures} part(s) do not match their recorded answer."
+        )));
+    }
+
+    Ok(())
+}
+
+fn verify_part(answers: &Answers, day: Day, part: u8, output: &DayAnswers) -> Verdict {
+    let Some(expected) = answers.expected(day, part) else {
+        return Verdict::Missing;
+    };
+
+    match output.get(part) {
+        Some(actual) if actual == expected => Verdict::Pass,
+        actual => Verdict::Fail(expected.clone(), actual.cloned()),
+    }
+}
@@ -0,0 +1,417 @@
+/// Settings of the project, read once from `aoc.toml` in the root of the repository.
+///
+/// Every setting has a default and can be overridden with an environment variable, which takes
+/// precedence over the file. `AOC_CONFIG` points to another file than `./aoc.toml`.
+use std::{
+    collections::{BTreeMap, HashMap},
+    env,
+    fmt::Display,
+    fs,
+    path::PathBuf,
+    sync::OnceLock,
+    time::Duration,
+};
+
+use crate::template::events::{self, DAYS_VAR_PREFIX};
+use crate::template::runner::DEFAULT_BUDGET;
+use crate::template::toml::{self, Table, Value};
+use crate::template::{Error, Year};
+
+static DEFAULT_CONFIG_PATH: &str = "./aoc.toml";
+static DEFAULT_DATA_DIR: &str = "./data";
+static DEFAULT_README_PATH: &str = "./README.md";
+static DEFAULT_BENCHMARKS_MARKER: &str = "benchmarking table";
+static DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+static DEFAULT_KEY_FILE: &str = "./.aoc.key";
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+/// Puzzles unlock at midnight EST (UTC-5).
+const DEFAULT_SERVER_UTC_OFFSET: i64 = -5;
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+/// Where the value of a setting comes from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Source {
+    Default,
+    File,
+    Env(String),
+}
+
+/// A resolved setting, as printed by `cargo config`.
+#[derive(Clone, Debug)]
+pub struct Setting {
+    pub key: String,
+    pub value: String,
+    pub source: Source,
+}
+
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// The file the settings were read from, `None` if there is none.
+    pub file: Option<PathBuf>,
+    /// Year used by commands that are run without `--year`.
+    pub year: Option<Year>,
+    /// Directory holding the data of every year, e.g. `./data/2024/inputs`.
+    pub data_dir: PathBuf,
+    /// Readme that `cargo time --store` writes the benchmark tables to.
+    pub readme: PathBuf,
+    /// Name of the markers around the benchmark table of a year, e.g. `<!--- benchmarking table 2024 --->`.
+    pub benchmarks_marker: String,
+    /// Time spent benching each part.
+    pub budget: Duration,
+    /// Base url of the Advent of Code website.
+    pub base_url: String,
+    /// File the session cookie is read from before the default locations.
+    pub session_file: Option<PathBuf>,
+    /// Timeout of requests to the website.
+    pub timeout: Duration,
+    /// Offset of the server's time zone to UTC in hours, puzzles unlock at midnight.
+    pub server_utc_offset: i32,
+    /// File the passphrase of encrypted inputs is read from, unless `AOC_KEY` is set.
+    pub key_file: PathBuf,
+    /// Number of days of events that differ from the default.
+    days: BTreeMap<Year, u8>,
+    settings: Vec<Setting>,
+}
+
+/// Read the settings of the project, if they were not read before.
+/// Binaries call this first, so an invalid config is reported before it is used.
+pub fn init() -> Result<&'static Config, Error> {
+    if let Some(config) = CONFIG.get() {
+        return Ok(config);
+    }
+    let config = Config::load().map_err(Error::Config)?;
+    Ok(CONFIG.get_or_init(|| config))
+}
+
+/// Settings of the project, read on first use.
+///
+/// # Panics
+/// If the config is invalid and [`init`] was not called before.
+pub fn get() -> &'static Config {
+    init().unwrap_or_else(|e| panic!("{e}"))
+}
+
+impl Config {
+    /// Read the config file and the environment.
+    pub fn load() -> Result<Self, String> {
+        let vars: HashMap<String, String> = env::vars().collect();
+
+        let (path, is_default) = match vars.get("AOC_CONFIG") {
+            Some(path) => (PathBuf::from(path), false),
+            None => (PathBuf::from(DEFAULT_CONFIG_PATH), true),
+        };
+
+        // the default file is optional, a file that was asked for is not.
+        let document = match fs::read_to_string(&path) {
+            Ok(document) => Some(document),
+            Err(_) if is_default && !path.exists() => None,
+            Err(e) => return Err(format!("\"{}\": {e}", path.display())),
+        };
+
+        let table = document
+            .as_deref()
+            .map(toml::parse)
+            .transpose()
+            .map_err(|e| format!("\"{}\", {e}", path.display()))?
+            .unwrap_or_default();
+
+        let mut config = Self::resolve(table, &vars)?;
+        config.file = document.is_some().then_some(path);
+        Ok(config)
+    }
+
+    /// Resolve the settings of a parsed config file, overridden by environment variables.
+    pub fn resolve(table: Table, vars: &HashMap<String, String>) -> Result<Self, String> {
+        let mut resolver = Resolver {
+            table,
+            vars,
+            settings: vec![],
+        };
+
+        let year = resolver
+            .integer("year", "AOC_YEAR", None)?
+            .map(|year| {
+                u16::try_from(year)
+                    .ok()
+                    .and_then(Year::new)
+                    .ok_or_else(|| format!("`year` must be 2015 or later, got {year}"))
+            })
+            .transpose()?;
+
+        let data_dir = resolver.string("data.dir", "AOC_DATA_DIR", Some(DEFAULT_DATA_DIR))?;
+        let readme = resolver.string("readme.path", "AOC_README", Some(DEFAULT_README_PATH))?;
+        let benchmarks_marker = resolver.string(
+            "readme.benchmarks_marker",
+            "AOC_BENCHMARKS_MARKER",
+            Some(DEFAULT_BENCHMARKS_MARKER),
+        )?;
+
+        let budget = resolver.integer(
+            "bench.budget",
+            "AOC_BUDGET",
+            Some(i64::try_from(DEFAULT_BUDGET.as_millis()).unwrap_or(i64::MAX)),
+        )?;
+
+        let base_url =
+            resolver.string("client.base_url", "AOC_BASE_URL", Some(DEFAULT_BASE_URL))?;
+        let session_file = resolver.string("client.session_file", "AOC_SESSION_FILE", None)?;
+        let timeout = resolver.integer(
+            "client.timeout",
+            "AOC_TIMEOUT",
+            Some(i64::try_from(DEFAULT_TIMEOUT.as_secs()).unwrap_or(i64::MAX)),
+        )?;
+        let server_utc_offset = resolver.integer(
+            "client.server_utc_offset",
+            "AOC_SERVER_UTC_OFFSET",
+            Some(DEFAULT_SERVER_UTC_OFFSET),
+        )?;
+
+        let key_file =
+            resolver.string("crypto.key_file", "AOC_KEY_FILE", Some(DEFAULT_KEY_FILE))?;
+
+        let days = resolver.days()?;
+
+        if let Some(key) = resolver.table.keys().next() {
+            return Err(format!("unknown setting `{key}`"));
+        }
+
+        let non_negative = |key: &str, n: Option<i64>| {
+            u64::try_from(n.unwrap_or_default())
+                .map_err(|_| format!("`{key}` must not be negative"))
+        };
+
+        let server_utc_offset = server_utc_offset
+            .and_then(|offset| i32::try_from(offset).ok())
+            .filter(|offset| (-12..=14).contains(offset))
+            .ok_or("`client.server_utc_offset` must be between -12 and 14")?;
+
+        let mut config = Self {
+            file: None,
+            year,
+            data_dir: data_dir.unwrap_or_default().into(),
+            readme: readme.unwrap_or_default().into(),
+            benchmarks_marker: benchmarks_marker.unwrap_or_default(),
+            budget: Duration::from_millis(non_negative("bench.budget", budget)?),
+            base_url: base_url.unwrap_or_default(),
+            session_file: session_file.map(PathBuf::from),
+            timeout: Duration::from_secs(non_negative("client.timeout", timeout)?),
+            server_utc_offset,
+            key_file: key_file.unwrap_or_default().into(),
+            days,
+            settings: resolver.settings,
+        };
+
+        // the days of the configured year are shown even if they are not overridden.
+        if let Some(year) = year.filter(|year| !config.days.contains_key(year)) {
+            config.settings.push(Setting {
+                key: format!("days.{year}"),
+                value: config.days(year).to_string(),
+                source: Source::Default,
+            });
+        }
+
+        Ok(config)
+    }
+
+    /// Number of days of the event of a year: 25 until 2024, 12 since 2025, unless configured.
+    pub fn days(&self, year: Year) -> u8 {
+        events::days(year.into_inner(), self.days.get(&year).copied())
+    }
+
+    /// Every setting with its resolved value and where it comes from.
+    pub fn settings(&self) -> &[Setting] {
+        &self.settings
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::Default => f.write_str("default"),
+            Source::File => f.write_str("file"),
+            Source::Env(var) => write!(f, "env {var}"),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Looks up settings in the environment and then the file, and records where they come from.
+struct Resolver<'a> {
+    /// Keys of the file that were not looked up yet.
+    table: Table,
+    vars: &'a HashMap<String, String>,
+    settings: Vec<Setting>,
+}
+
+impl Resolver<'_> {
+    fn lookup(&mut self, key: &str, var: &str) -> Option<(Value, Source)> {
+        let file_value = self.table.remove(key);
+
+        match self.vars.get(var).filter(|value| !value.is_empty()) {
+            Some(value) => Some((Value::String(value.clone()), Source::Env(var.into()))),
+            None => file_value.map(|value| (value, Source::File)),
+        }
+    }
+
+    fn record(&mut self, key: &str, value: String, source: Source) {
+        self.settings.push(Setting {
+            key: key.into(),
+            value,
+            source,
+        });
+    }
+
+    fn string(
+        &mut self,
+        key: &str,
+        var: &str,
+        default: Option<&str>,
+    ) -> Result<Option<String>, String> {
+        let (value, source) = match self.lookup(key, var) {
+            Some((Value::String(value), source)) => (Some(value), source),
+            Some(_) => return Err(format!("`{key}` must be a string")),
+            None => (default.map(str::to_string), Source::Default),
+        };
+
+        let shown = value
+            .as_ref()
+            .map_or("-".into(), |value| format!("{value:?}"));
+        self.record(key, shown, source);
+        Ok(value)
+    }
+
+    fn integer(
+        &mut self,
+        key: &str,
+        var: &str,
+        default: Option<i64>,
+    ) -> Result<Option<i64>, String> {
+        let (value, source) = match self.lookup(key, var) {
+            Some((Value::Integer(value), source)) => (Some(value), source),
+            // values of environment variables are always strings.
+            Some((Value::String(value), source @ Source::Env(_))) => {
+                let value = value
+                    .trim()
+                    .parse()
+                    .map_err(|_| format!("`{var}` must be an integer, got `{value}`"))?;
+                (Some(value), source)
+            }
+            Some(_) => return Err(format!("`{key}` must be an integer")),
+            None => (default, Source::Default),
+        };
+
+        let shown = value.map_or("-".into(), |value| value.to_string());
+        self.record(key, shown, source);
+        Ok(value)
+    }
+
+    /// Number of days of events, from the `[days]` table and `AOC_DAYS_<year>` variables.
+    fn days(&mut self) -> Result<BTreeMap<Year, u8>, String> {
+        let mut years: Vec<String> = self
+            .table
+            .keys()
+            .filter_map(|key| key.strip_prefix("days."))
+            .chain(
+                self.vars
+                    .keys()
+                    .filter_map(|var| var.strip_prefix(DAYS_VAR_PREFIX)),
+            )
+            .map(str::to_string)
+            .collect();
+
+        years.sort_unstable();
+        years.dedup();
+
+        let mut days = BTreeMap::new();
+
+        for year in years {
+            let key = events::key(&year);
+            let var = events::var(&year);
+
+            let Some(count) = self.integer(&key, &var, None)? else {
+                continue;
+            };
+
+            let year: Year = year
+                .parse()
+                .map_err(|e| format!("invalid year in `{key}`: {e}"))?;
+
+            days.insert(year, events::check(&key, count)?);
+        }
+
+        Ok(days)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    use super::{Config, Source};
+    use crate::template::toml;
+    use crate::year;
+
+    fn resolve(document: &str, vars: &[(&str, &str)]) -> Result<Config, String> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(var, value)| (var.to_string(), value.to_string()))
+            .collect();
+        Config::resolve(toml::parse(document).unwrap(), &vars)
+    }
+
+    #[test]
+    fn uses_defaults() {
+        let config = resolve("", &[]).unwrap();
+        assert_eq!(config.year, None);
+        assert_eq!(config.data_dir, PathBuf::from("./data"));
+        assert_eq!(config.budget, Duration::from_secs(1));
+        assert_eq!(config.days(year!(2024)), 25);
+        assert_eq!(config.days(year!(2025)), 12);
+    }
+
+    #[test]
+    fn reads_the_file() {
+        let config = resolve(
+            "year = 2023\n[data]\ndir = \"puzzles\"\n[bench]\nbudget = 250\n[days]\n2023 = 12",
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(config.year, Some(year!(2023)));
+        assert_eq!(config.data_dir, PathBuf::from("puzzles"));
+        assert_eq!(config.budget, Duration::from_millis(250));
+        assert_eq!(config.days(year!(2023)), 12);
+    }
+
+    #[test]
+    fn prefers_environment_variables() {
+        let config = resolve(
+            "year = 2023\n[days]\n2023 = 12",
+            &[("AOC_YEAR", "2022"), ("AOC_DAYS_2023", "20")],
+        )
+        .unwrap();
+
+        assert_eq!(config.year, Some(year!(2022)));
+        assert_eq!(config.days(year!(2023)), 20);
+
+        let year = config.settings().iter().find(|s| s.key == "year").unwrap();
+        assert_eq!(year.source, Source::Env("AOC_YEAR".into()));
+    }
+
+    #[test]
+    fn rejects_invalid_settings() {
+        assert!(resolve("year = 2014", &[]).is_err());
+        assert!(resolve("[bench]\nbudget = \"fast\"", &[]).is_err());
+        assert!(resolve("[days]\n2025 = 30", &[]).is_err());
+        assert_eq!(
+            resolve("[bench]\nbugdet = 100", &[]).unwrap_err(),
+            "unknown setting `bench.bugdet`"
+        );
+    }
+}
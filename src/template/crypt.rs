@@ -0,0 +1,215 @@
+/// Encrypted storage of puzzle inputs and descriptions, which are not supposed to be published.
+/// Encrypted copies live next to the plain folders of a year, e.g. `data/2024/inputs.enc/01.txt.enc`,
+/// and can be committed. Plain files are read first, encrypted ones are decrypted on the fly.
+///
+/// The key is derived from a passphrase in the `AOC_KEY` env variable or, if not set, in the
+/// key file (see `crypto.key_file` in `aoc.toml`).
+use std::{
+    env, fs,
+    io::{self, ErrorKind},
+    path::{Path, PathBuf},
+};
+
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::{ChaCha20Poly1305, Nonce};
+use sha2::{Digest, Sha256};
+
+use crate::template::{config, Day, Year};
+
+/// Folders of a year that have an encrypted copy.
+pub const ENCRYPTED_DIRS: [&str; 2] = ["inputs", "puzzles"];
+
+/// Suffix of encrypted folders and files.
+const SUFFIX: &str = "enc";
+
+/// Start of every encrypted file, followed by the nonce and the ciphertext.
+const MAGIC: &[u8] = b"AOCENC1\n";
+
+const NONCE_LENGTH: usize = 12;
+
+/// A key derived from a passphrase. The passphrase is hashed without stretching, so it should be
+/// long and random, e.g. the output of `openssl rand -hex 32`.
+pub struct Key([u8; 32]);
+
+impl Key {
+    pub fn from_passphrase(passphrase: &str) -> Self {
+        Key(Sha256::digest(passphrase.trim().as_bytes()).into())
+    }
+
+    fn cipher(&self) -> ChaCha20Poly1305 {
+        ChaCha20Poly1305::new(&self.0.into())
+    }
+
+    /// The key from `AOC_KEY` or the key file, `None` if neither is set.
+    pub fn from_env() -> Option<Self> {
+        env::var("AOC_KEY")
+            .ok()
+            .or_else(|| fs::read_to_string(&config::get().key_file).ok())
+            .filter(|passphrase| !passphrase.trim().is_empty())
+            .map(|passphrase| Self::from_passphrase(&passphrase))
+    }
+
+    /// Encrypt a file's contents. The nonce is derived from the key and the contents, so encrypting
+    /// the same contents again gives the same file and unchanged inputs do not show up in `git status`.
+    pub fn encrypt(&self, plaintext: &[u8]) -> Vec<u8> {
+        let mut hasher = Sha256::new();
+        hasher.update(self.0);
+        hasher.update(plaintext);
+        let hash = hasher.finalize();
+        let nonce = Nonce::from_slice(&hash[..NONCE_LENGTH]);
+
+        // encrypting into a `Vec` cannot fail.
+        let ciphertext = self.cipher().encrypt(nonce, plaintext).unwrap();
+        [MAGIC, nonce.as_slice(), &ciphertext].concat()
+    }
+
+    /// Decrypt a file's contents, failing if the key is wrong or the file was changed.
+    pub fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>, String> {
+        let data = data
+            .strip_prefix(MAGIC)
+            .filter(|data| data.len() >= NONCE_LENGTH)
+            .ok_or("not an encrypted file")?;
+        let (nonce, ciphertext) = data.split_at(NONCE_LENGTH);
+
+        self.cipher()
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| "wrong key or corrupted file".into())
+    }
+}
+
+/// Path of the encrypted copy of a file in one of `ENCRYPTED_DIRS`,
+/// e.g. `data/2024/inputs.enc/01.txt.enc` for `data/2024/inputs/01.txt`.
+pub fn encrypted_path(path: &Path) -> Option<PathBuf> {
+    let dir = path.parent()?;
+    let name = path.file_name()?.to_str()?;
+    let dir_name = dir.file_name()?.to_str()?;
+
+    ENCRYPTED_DIRS.contains(&dir_name).then(|| {
+        dir.with_file_name(format!("{dir_name}.{SUFFIX}"))
+            .join(format!("{name}.{SUFFIX}"))
+    })
+}
+
+/// Path of the plain file of an encrypted copy, the inverse of [`encrypted_path`].
+pub fn plain_path(path: &Path) -> Option<PathBuf> {
+    let dir = path.parent()?;
+    let name = path
+        .file_name()?
+        .to_str()?
+        .strip_suffix(&format!(".{SUFFIX}"))?;
+    let dir_name = dir
+        .file_name()?
+        .to_str()?
+        .strip_suffix(&format!(".{SUFFIX}"))?;
+
+    ENCRYPTED_DIRS
+        .contains(&dir_name)
+        .then(|| dir.with_file_name(dir_name).join(name))
+}
+
+/// Read a file, decrypting its encrypted copy if the file does not exist or is empty,
+/// e.g. an input file created by `scaffold`.
+pub fn read_to_string(path: &Path) -> io::Result<String> {
+    let encrypted = encrypted_path(path).filter(|path| path.is_file());
+
+    match fs::read_to_string(path) {
+        Ok(contents) if contents.trim().is_empty() && encrypted.is_some() => decrypt_file(path),
+        Err(e) if e.kind() == ErrorKind::NotFound && encrypted.is_some() => decrypt_file(path),
+        result => result,
+    }
+}
+
+/// Decrypt the encrypted copy of a file.
+fn decrypt_file(path: &Path) -> io::Result<String> {
+    let encrypted = encrypted_path(path).ok_or(ErrorKind::NotFound)?;
+
+    let key = Key::from_env().ok_or_else(|| {
+        io::Error::other(format!(
+            "\"{}\" is encrypted, {}",
+            encrypted.display(),
+            missing_key()
+        ))
+    })?;
+
+    let plaintext = key
+        .decrypt(&fs::read(&encrypted)?)
+        .map_err(|e| io::Error::other(format!("\"{}\": {e}", encrypted.display())))?;
+    String::from_utf8(plaintext).map_err(|e| io::Error::new(ErrorKind::InvalidData, e))
+}
+
+/// Explains how to provide a key.
+pub fn missing_key() -> String {
+    format!(
+        "set `AOC_KEY` or write a passphrase to the key file \"{}\"",
+        config::get().key_file.display()
+    )
+}
+
+/// Files of a year in one of `ENCRYPTED_DIRS`, or in their encrypted copies if `encrypted` is set.
+pub fn files(year: Year, encrypted: bool) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = ENCRYPTED_DIRS
+        .iter()
+        .map(|dir| {
+            if encrypted {
+                year.data_dir().join(format!("{dir}.{SUFFIX}"))
+            } else {
+                year.data_dir().join(dir)
+            }
+        })
+        .filter_map(|dir| fs::read_dir(dir).ok())
+        .flat_map(|entries| entries.filter_map(Result::ok).map(|entry| entry.path()))
+        // dotfiles like `.keep` are not puzzle data.
+        .filter(|path| {
+            path.is_file()
+                && !path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.starts_with('.'))
+        })
+        .collect();
+
+    files.sort_unstable();
+    files
+}
+
+/// Whether a plain file belongs to a day, e.g. `inputs/01.txt` and `puzzles/01.md` to day 1.
+/// Every file belongs to `None`.
+pub fn is_of_day(path: &Path, day: Option<Day>) -> bool {
+    day.is_none_or(|day| {
+        path.file_stem()
+            .and_then(|stem| stem.to_str())
+            .is_some_and(|stem| stem == day.to_string())
+    })
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{encrypted_path, plain_path, Key};
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn encrypts_and_decrypts() {
+        let key = Key::from_passphrase("correct horse battery staple\n");
+        let encrypted = key.encrypt(b"1,2\n3,4\n");
+
+        assert_eq!(encrypted, key.encrypt(b"1,2\n3,4\n"));
+        assert_eq!(key.decrypt(&encrypted).unwrap(), b"1,2\n3,4\n");
+        assert!(Key::from_passphrase("wrong").decrypt(&encrypted).is_err());
+        assert!(key.decrypt(b"1,2\n3,4\n").is_err());
+    }
+
+    #[test]
+    fn maps_paths() {
+        let plain = Path::new("./data/2024/inputs/01.txt");
+        let encrypted = PathBuf::from("./data/2024/inputs.enc/01.txt.enc");
+
+        assert_eq!(encrypted_path(plain), Some(encrypted.clone()));
+        assert_eq!(plain_path(&encrypted), Some(plain.to_path_buf()));
+        assert_eq!(
+            encrypted_path(Path::new("./data/2024/examples/01.txt")),
+            None
+        );
+    }
+}
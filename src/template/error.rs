@@ -0,0 +1,121 @@
+/// Errors of the template's commands. Commands return them instead of exiting, `main` prints
+/// them and exits with the code of their class, see [`Error::exit_code`].
+use std::fmt::Display;
+
+use crate::template::aoc_client::AocCommandError;
+
+#[derive(Debug)]
+pub enum Error {
+    /// Answers did not match their recorded or expected ones, or benchmarks regressed.
+    Check(String),
+    /// Invalid command-line input.
+    Parse(String),
+    /// The config file or one of its environment variables is invalid.
+    Config(String),
+    /// A file could not be read or written.
+    Io(String),
+    /// The input does not meet the expectations of the day.
+    Input(String),
+    /// A request to the Advent of Code website failed.
+    Client(AocCommandError),
+    /// A solution panicked.
+    Panicked,
+    /// A solution binary run by `solve` failed with its exit code, `None` if it was killed by a signal.
+    Exited(Option<i32>),
+}
+
+impl Error {
+    /// Exit code of the process:
+    ///  - `1`: a check failed, e.g. `cargo verify` found a wrong answer.
+    ///  - `2`: invalid command-line input.
+    ///  - `3`: invalid config.
+    ///  - `4`: a file could not be read or written.
+    ///  - `5`: a request to the website failed.
+    ///  - `6`: the input does not meet the expectations of the day.
+    ///  - `7`: the solution binary that `solve` ran was killed without an exit status.
+    ///  - `101`: a solution panicked, like the exit code of a panicking Rust program.
+    ///  - any other exit code of the solution binary that `solve` ran is passed through, even if it
+    ///    coincides with one of the codes above.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Error::Check(_) => 1,
+            Error::Parse(_) => 2,
+            Error::Config(_) => 3,
+            Error::Io(_) => 4,
+            Error::Client(_) => 5,
+            Error::Input(_) => 6,
+            Error::Panicked => 101,
+            Error::Exited(code) => code.unwrap_or(7),
+        }
+    }
+
+    /// Wraps an error of an I/O operation, e.g. `.map_err(Error::io("Failed to create module file"))`.
+    pub fn io<E: Display>(context: &str) -> impl FnOnce(E) -> Self + '_ {
+        move |e| Error::Io(format!("{context}: {e}"))
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Check(message)
+            | Error::Parse(message)
+            | Error::Io(message)
+            | Error::Input(message) => f.write_str(message),
+            Error::Config(message) => write!(f, "invalid config, {message}"),
+            Error::Client(e) => write!(f, "request to adventofcode.com failed, {e}"),
+            Error::Panicked => f.write_str("the solution panicked."),
+            Error::Exited(Some(code)) => write!(f, "the solution exited with status {code}."),
+            Error::Exited(None) => f.write_str("the solution was killed by a signal."),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<AocCommandError> for Error {
+    fn from(e: AocCommandError) -> Self {
+        match e {
+            // files written by the client, e.g. a downloaded input.
+            AocCommandError::IO(e) => Error::Io(e.to_string()),
+            e => Error::Client(e),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::Error;
+    use crate::template::aoc_client::AocCommandError;
+    use std::io;
+
+    #[test]
+    fn has_distinct_exit_codes() {
+        let errors = [
+            Error::Check(String::new()),
+            Error::Parse(String::new()),
+            Error::Config(String::new()),
+            Error::Io(String::new()),
+            Error::Client(AocCommandError::Unauthorized),
+            Error::Input(String::new()),
+            Error::Exited(None),
+            Error::Panicked,
+        ];
+
+        let mut codes: Vec<i32> = errors.iter().map(Error::exit_code).collect();
+        codes.sort_unstable();
+        codes.dedup();
+        assert_eq!(codes.len(), errors.len());
+        assert!(!codes.contains(&0));
+    }
+
+    #[test]
+    fn classifies_client_errors() {
+        let io = io::Error::new(io::ErrorKind::PermissionDenied, "denied");
+        assert_eq!(Error::from(AocCommandError::IO(io)).exit_code(), 4);
+        assert_eq!(Error::from(AocCommandError::PuzzleLocked).exit_code(), 5);
+        assert_eq!(Error::Exited(Some(42)).exit_code(), 42);
+    }
+}
@@ -0,0 +1,31 @@
+//! Number of days of an event. The build script includes this file with `#[path]`, so solutions
+//! for days after the last day of their event are rejected by the same rule as the commands use.
+
+/// Prefix of the variables overriding the number of days of an event, e.g. `AOC_DAYS_2025`.
+pub static DAYS_VAR_PREFIX: &str = "AOC_DAYS_";
+
+/// The first year with an event of 12 days, all earlier events have 25.
+const FIRST_SHORT_YEAR: u16 = 2025;
+
+/// Key of the number of days of a year in `aoc.toml`, e.g. `days.2025`.
+pub fn key(year: &str) -> String {
+    format!("days.{year}")
+}
+
+/// Variable overriding the number of days of a year, e.g. `AOC_DAYS_2025`.
+pub fn var(year: &str) -> String {
+    format!("{DAYS_VAR_PREFIX}{year}")
+}
+
+/// Check the number of days configured with `key`.
+pub fn check(key: &str, count: i64) -> Result<u8, String> {
+    u8::try_from(count)
+        .ok()
+        .filter(|count| (1..=25).contains(count))
+        .ok_or_else(|| format!("`{key}` must be between 1 and 25, got {count}"))
+}
+
+/// Number of days of the event of a year: the `configured` number, or 25 until 2024 and 12 since.
+pub fn days(year: u16, configured: Option<u8>) -> u8 {
+    configured.unwrap_or(if year < FIRST_SHORT_YEAR { 25 } else { 12 })
+}
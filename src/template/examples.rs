@@ -0,0 +1,425 @@
+/// Example inputs in `data/<year>/examples` and the answers expected for them.
+/// The first example of a day is `NN.txt`, any further example `n` is `NN-n.txt`.
+use regex::Regex;
+use std::{
+    collections::HashMap,
+    fs,
+    io::{self, ErrorKind},
+    path::PathBuf,
+    str::FromStr,
+};
+use tinyjson::JsonValue;
+
+use crate::template::runner::Solution;
+use crate::template::{input, Day, Error, Puzzle, Year};
+
+/// Name of the directory of examples in the data directory of a year.
+static EXAMPLES_DIR_NAME: &str = "examples";
+static ANSWERS_FILE_NAME: &str = "answers.json";
+
+/// Directory of the examples of a year, e.g. `data/2024/examples`.
+fn get_dir(year: Year) -> PathBuf {
+    year.data_dir().join(EXAMPLES_DIR_NAME)
+}
+
+/// Path of an example of a puzzle.
+pub fn get_path(puzzle: Puzzle, example: u8) -> PathBuf {
+    let day = puzzle.day;
+    let file_name = if example <= 1 {
+        format!("{day}.txt")
+    } else {
+        format!("{day}-{example}.txt")
+    };
+
+    get_dir(puzzle.year).join(file_name)
+}
+
+/// Numbers of the examples present for a puzzle, in ascending order.
+pub fn find(puzzle: Puzzle) -> Vec<u8> {
+    let Ok(entries) = fs::read_dir(get_dir(puzzle.year)) else {
+        return vec![];
+    };
+
+    let mut examples: Vec<u8> = entries
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter_map(|name| parse_file_name(puzzle.day, &name))
+        .collect();
+
+    examples.sort_unstable();
+    examples
+}
+
+/// Number of the example stored in a file of the given name, if it belongs to the day.
+fn parse_file_name(day: Day, name: &str) -> Option<u8> {
+    let stem = name.strip_suffix(".txt")?.strip_prefix(&day.to_string())?;
+
+    match stem.strip_prefix('-') {
+        None if stem.is_empty() => Some(1),
+        Some(example) => example.parse().ok().filter(|n| *n > 1),
+        None => None,
+    }
+}
+
+/// Run a part on an example and assert that it returns the expected answer.
+/// Used by the tests that `solution!` generates from `data/<year>/examples/answers.json`.
+/// The example is loaded and checked like the runner does, see [`input::load`].
+pub fn check(
+    solution: &Solution,
+    example: u8,
+    part: u8,
+    expected: &str,
+    solve: impl Fn(&str, u8) -> Result<Option<String>, String>,
+) {
+    let path = get_path(solution.puzzle, example);
+    let input = input::load(&path)
+        .unwrap_or_else(|e| panic!("could not read example file \"{}\": {e}", path.display()));
+
+    if let Err(e) = solution.check_input(&input) {
+        panic!("example {example}: {e}");
+    }
+
+    assert_eq!(
+        solve(&input, part),
+        Ok(Some(expected.to_string())),
+        "wrong answer for part {part} of example {example}."
+    );
+}
+
+/// The expected answer of a part for one example of a day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExampleAnswer {
+    pub day: Day,
+    pub example: u8,
+    pub part: u8,
+    pub answer: String,
+}
+
+/// Expected answers of all examples of a year, stored next to them.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct ExampleAnswers {
+    pub data: Vec<ExampleAnswer>,
+}
+
+impl ExampleAnswers {
+    /// Dehydrate expected answers to a JSON file.
+    pub fn store_file(&self, year: Year) -> Result<(), io::Error> {
+        let json = JsonValue::from(self.clone());
+        fs::create_dir_all(get_dir(year))?;
+        let mut file = fs::File::create(get_dir(year).join(ANSWERS_FILE_NAME))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate expected answers from a JSON file. If not present, returns empty answers.
+    /// Fails if the file cannot be read or parsed, so the answers are not overwritten when storing.
+    pub fn read_from_file(year: Year) -> Result<Self, Error> {
+        let path = get_dir(year).join(ANSWERS_FILE_NAME);
+        let context = format!("Failed to read example answers from \"{}\"", path.display());
+
+        match fs::read_to_string(&path) {
+            Ok(json) => ExampleAnswers::try_from(json).map_err(Error::io(&context)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(Error::io(&context)(e)),
+        }
+    }
+
+    pub fn get(&self, day: Day, example: u8, part: u8) -> Option<&String> {
+        self.data
+            .iter()
+            .find(|a| a.day == day && a.example == example && a.part == part)
+            .map(|a| &a.answer)
+    }
+
+    /// Set the expected answer of a part for an example, replacing a previous one.
+    pub fn set(&mut self, day: Day, example: u8, part: u8, answer: &str) {
+        self.data
+            .retain(|a| !(a.day == day && a.example == example && a.part == part));
+        self.data.push(ExampleAnswer {
+            day,
+            example,
+            part,
+            answer: answer.into(),
+        });
+        self.data
+            .sort_unstable_by_key(|a| (a.day, a.example, a.part));
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A code block of a puzzle description.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CodeBlock {
+    /// The part whose description contains the block.
+    pub part: u8,
+    pub content: String,
+    /// Whether the text leading up to the block mentions an example.
+    pub is_example: bool,
+}
+
+/// Code blocks of a puzzle description in the markdown written by `cargo download`, in order.
+pub fn code_blocks(markdown: &str) -> Vec<CodeBlock> {
+    let mut blocks = vec![];
+    let mut part = 1;
+    let mut preceding = "";
+    let mut lines = markdown.lines();
+
+    while let Some(line) = lines.next() {
+        if line.starts_with("## ") && line.contains("Part Two") {
+            part = 2;
+        } else if line.starts_with("```") {
+            let content: Vec<&str> = lines
+                .by_ref()
+                .take_while(|l| !l.starts_with("```"))
+                .collect();
+            blocks.push(CodeBlock {
+                part,
+                content: content.join("\n") + "\n",
+                is_example: preceding.to_lowercase().contains("example"),
+            });
+        } else if !line.trim().is_empty() {
+            preceding = line;
+        }
+    }
+
+    blocks
+}
+
+/// Index of the block that most likely holds the example input of a part.
+/// This is the first block of the part that is introduced by text mentioning an example.
+pub fn likely_example(blocks: &[CodeBlock], part: u8) -> Option<usize> {
+    blocks
+        .iter()
+        .position(|block| block.part == part && block.is_example)
+}
+
+/// Answers that the description of each part gives for its example.
+/// The answer is taken to be the last emphasized code in the text of a part, e.g. `*11*`.
+pub fn find_answers(markdown: &str) -> Vec<(u8, String)> {
+    let emphasized = Regex::new(r"`\*([^`*]+)\*`|\*`([^`*]+)`\*").unwrap();
+    let mut answers: Vec<(u8, String)> = vec![];
+    let mut part = 1;
+    let mut in_block = false;
+
+    for line in markdown.lines() {
+        if line.starts_with("```") {
+            in_block = !in_block;
+            continue;
+        }
+
+        if in_block {
+            continue;
+        }
+
+        if line.starts_with("## ") && line.contains("Part Two") {
+            part = 2;
+            continue;
+        }
+
+        // puzzle pages of solved parts state the accepted answer, which is not an example answer.
+        if line.starts_with("Your puzzle answer was") {
+            continue;
+        }
+
+        if let Some(answer) = emphasized
+            .captures_iter(line)
+            .filter_map(|c| c.get(1).or(c.get(2)))
+            .last()
+        {
+            answers.retain(|(p, _)| *p != part);
+            answers.push((part, answer.as_str().trim().to_string()));
+        }
+    }
+
+    answers
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<ExampleAnswers> for JsonValue {
+    fn from(value: ExampleAnswers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for ExampleAnswers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(ExampleAnswers {
+            data: json_data
+                .iter()
+                .map(ExampleAnswer::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&ExampleAnswer> for JsonValue {
+    fn from(value: &ExampleAnswer) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert(
+            "example".into(),
+            JsonValue::Number(f64::from(value.example)),
+        );
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for ExampleAnswer {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected example answer to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected example.day to be a Day struct.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .map(|n| n as u8)
+                .ok_or(format!("Expected example.{key} to be a number."))
+        };
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected example.answer to be a string.")?;
+
+        Ok(ExampleAnswer {
+            day,
+            example: number("example")?,
+            part: number("part")?,
+            answer: answer.clone(),
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{
+        code_blocks, find_answers, get_path, likely_example, parse_file_name, ExampleAnswers,
+    };
+    use crate::template::Puzzle;
+    use crate::{day, year};
+
+    #[test]
+    fn names_example_files() {
+        let puzzle = Puzzle::new(year!(2024), day!(3));
+        assert_eq!(
+            get_path(puzzle, 1),
+            PathBuf::from("./data/2024/examples/03.txt")
+        );
+        assert_eq!(
+            get_path(puzzle, 2),
+            PathBuf::from("./data/2024/examples/03-2.txt")
+        );
+    }
+
+    #[test]
+    fn parses_example_files() {
+        assert_eq!(parse_file_name(day!(15), "15.txt"), Some(1));
+        assert_eq!(parse_file_name(day!(15), "15-3.txt"), Some(3));
+        assert_eq!(parse_file_name(day!(15), "15-1.txt"), None);
+        assert_eq!(parse_file_name(day!(15), "16.txt"), None);
+        assert_eq!(parse_file_name(day!(15), "15.md"), None);
+        assert_eq!(parse_file_name(day!(1), "01-x.txt"), None);
+    }
+
+    #[test]
+    fn reads_example_answers() {
+        let json = r#"{ "data": [{ "day": "15", "example": 2, "part": 1, "answer": "10092" }] }"#
+            .to_string();
+        let answers = ExampleAnswers::try_from(json).unwrap();
+        assert_eq!(answers.get(day!(15), 2, 1), Some(&"10092".to_string()));
+        assert_eq!(answers.get(day!(15), 1, 1), None);
+    }
+
+    #[test]
+    fn stores_example_answers_in_order() {
+        let mut answers = ExampleAnswers::default();
+        answers.set(day!(15), 2, 1, "10092");
+        answers.set(day!(15), 1, 1, "2028");
+        answers.set(day!(15), 2, 1, "10093");
+        assert_eq!(answers.data.len(), 2);
+        assert_eq!(answers.data[0].answer, "2028");
+        assert_eq!(answers.get(day!(15), 2, 1), Some(&"10093".to_string()));
+    }
+
+    const PUZZLE: &str = "## --- Day 1: Historian Hysteria ---
+
+Throughout the Chief's office, the historically significant locations are listed by `ID`:
+
+```
+ID
+```
+
+For example:
+
+```
+3   4
+4   3
+```
+
+In the example list above, the total distance is `2 + 1`, which gives `*11*`!
+
+## --- Part Two ---
+
+For these example lists, the similarity score is *`31`*.
+
+Your puzzle answer was `*1830467*`.
+";
+
+    #[test]
+    fn extracts_example_input() {
+        let blocks = code_blocks(PUZZLE);
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[1].content, "3   4\n4   3\n");
+        assert_eq!(blocks[1].part, 1);
+        assert_eq!(likely_example(&blocks, 1), Some(1));
+        assert_eq!(likely_example(&blocks, 2), None);
+    }
+
+    #[test]
+    fn extracts_example_answers() {
+        assert_eq!(
+            find_answers(PUZZLE),
+            vec![(1, "11".to_string()), (2, "31".to_string())]
+        );
+    }
+}
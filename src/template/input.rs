@@ -0,0 +1,162 @@
+/// Loading of puzzle inputs. Inputs are normalised before they are passed to a solution, so a
+/// file checked out with CRLF line endings or saved with trailing blank lines solves the same.
+/// Days can declare expectations on their input, which are checked before solving.
+use std::{fmt::Display, io, path::Path};
+
+use crate::template::crypt;
+
+/// Start of the page that is downloaded instead of the input without a valid session cookie.
+const LOGGED_OUT_INPUT: &str = "Puzzle inputs differ by user.";
+
+/// Normalise an input:
+///  - line endings are converted to `\n` and a byte order mark is removed.
+///  - trailing blank lines are removed, a non-empty input ends with exactly one `\n`.
+pub fn normalize(input: &str) -> String {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let lines: Vec<&str> = input.lines().collect();
+    let end = lines
+        .iter()
+        .rposition(|line| !line.trim().is_empty())
+        .map_or(0, |i| i + 1);
+
+    let mut normalized = lines[..end].join("\n");
+    if !normalized.is_empty() {
+        normalized.push('\n');
+    }
+    normalized
+}
+
+/// Why an input is likely not the real one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Warning {
+    /// The input is empty, e.g. the file created by `scaffold` before the input was downloaded.
+    Empty,
+    /// The input is the page shown to logged out users instead of the input.
+    LoggedOut,
+}
+
+impl Display for Warning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Warning::Empty => write!(f, "is empty"),
+            Warning::LoggedOut => write!(
+                f,
+                "is the page for logged out users, configure your session cookie and download it again"
+            ),
+        }
+    }
+}
+
+/// Check a normalised input for signs that it is a placeholder.
+pub fn warning(input: &str) -> Option<Warning> {
+    if input.trim().is_empty() {
+        Some(Warning::Empty)
+    } else if input.starts_with(LOGGED_OUT_INPUT) {
+        Some(Warning::LoggedOut)
+    } else {
+        None
+    }
+}
+
+/// Normalise an input and print a warning if it is likely not the real one.
+pub fn prepare(input: &str, label: &str) -> String {
+    let input = normalize(input);
+    if let Some(warning) = warning(&input) {
+        eprintln!("Warning: input {label} {warning}.");
+    }
+    input
+}
+
+/// Read and normalise an input file, decrypting its encrypted copy if it does not exist.
+pub fn load(path: &Path) -> io::Result<String> {
+    let input = crypt::read_to_string(path)?;
+    Ok(prepare(&input, &format!("\"{}\"", path.display())))
+}
+
+/// An expectation of a day on its input, declared with `input = [...]` in `solution!`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Expect {
+    /// Every line has the same length.
+    Grid,
+    /// The given number of sections, separated by blank lines.
+    Sections(usize),
+}
+
+impl Expect {
+    /// Check a normalised input, the error describes the first mismatch.
+    pub fn check(self, input: &str) -> Result<(), String> {
+        match self {
+            Expect::Grid => {
+                let mut lines = input.lines().enumerate();
+                let Some((_, first)) = lines.next() else {
+                    return Err("expected a grid, the input is empty".into());
+                };
+
+                let width = first.chars().count();
+                match lines.find(|(_, line)| line.chars().count() != width) {
+                    Some((i, line)) => Err(format!(
+                        "expected a rectangular grid, line {} has {} characters instead of {width}",
+                        i + 1,
+                        line.chars().count()
+                    )),
+                    None => Ok(()),
+                }
+            }
+            Expect::Sections(count) => {
+                let sections = input
+                    .split("\n\n")
+                    .filter(|section| !section.trim().is_empty())
+                    .count();
+
+                if sections == count {
+                    Ok(())
+                } else {
+                    Err(format!(
+                        "expected {count} section(s) separated by blank lines, found {sections}"
+                    ))
+                }
+            }
+        }
+    }
+}
+
+/// Check an input against all expectations of a day.
+pub fn check(input: &str, expectations: &[Expect]) -> Result<(), String> {
+    expectations
+        .iter()
+        .try_for_each(|expectation| expectation.check(input))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{check, normalize, warning, Expect, Warning};
+
+    #[test]
+    fn normalizes_inputs() {
+        assert_eq!(
+            normalize("\u{feff}1,2\r\n\r\n3,4\r\n\r\n\n"),
+            "1,2\n\n3,4\n"
+        );
+        assert_eq!(normalize("#.\n.#"), "#.\n.#\n");
+        assert_eq!(normalize("  \n\n"), "");
+        assert_eq!(warning(""), Some(Warning::Empty));
+        assert_eq!(
+            warning("Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"),
+            Some(Warning::LoggedOut)
+        );
+    }
+
+    #[test]
+    fn checks_expectations() {
+        assert_eq!(check("#.\n.#\n", &[Expect::Grid]), Ok(()));
+        assert_eq!(
+            check("#.\n.#.\n", &[Expect::Grid]).unwrap_err(),
+            "expected a rectangular grid, line 2 has 3 characters instead of 2"
+        );
+        assert_eq!(check("a\nb\n\nc\n", &[Expect::Sections(2)]), Ok(()));
+        assert!(check("a\nb\n", &[Expect::Sections(2)]).is_err());
+        assert!(check("", &[Expect::Grid]).is_err());
+    }
+}
@@ -1,68 +1,199 @@
-use std::{env, fs};
+/// Like `print!`, but collected instead when called inside `runner::capture`.
+macro_rules! out {
+    ($($arg:tt)*) => {
+        $crate::template::runner::write_output(&format!($($arg)*))
+    };
+}
 
-pub mod aoc_cli;
+/// Like `println!`, but collected instead when called inside `runner::capture`.
+macro_rules! outln {
+    () => {
+        $crate::template::runner::write_output("\n")
+    };
+    ($($arg:tt)*) => {
+        $crate::template::runner::write_output(&format!("{}\n", format_args!($($arg)*)))
+    };
+}
+
+pub mod aoc_client;
 pub mod commands;
+pub mod config;
+pub mod examples;
+pub mod input;
 pub mod runner;
 
 pub use day::*;
+pub use error::Error;
+pub use report::Format;
+pub use run_multi::Mode;
+pub use year::*;
 
+mod answers;
+mod crypt;
 mod day;
+mod error;
+mod events;
 mod readme_benchmarks;
+mod record;
+mod report;
 mod run_multi;
+mod templates;
 mod timings;
+mod toml;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
+pub const ANSI_RED: &str = "\x1b[31m";
+pub const ANSI_GREEN: &str = "\x1b[32m";
+pub const ANSI_YELLOW: &str = "\x1b[33m";
 
-/// Helper function that reads a text file to a string.
+/// Helper function that reads a text file of a puzzle's year to a string, e.g. `data/2024/examples/01.txt`.
+/// The contents are normalised, see [`input::normalize`].
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+pub fn read_file(folder: &str, puzzle: Puzzle) -> String {
+    let filepath = puzzle
+        .year
+        .data_dir()
+        .join(folder)
+        .join(format!("{}.txt", puzzle.day));
+    input::load(&filepath).expect("could not open input file")
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd
-        .join("data")
+pub fn read_file_part(folder: &str, puzzle: Puzzle, part: u8) -> String {
+    let filepath = puzzle
+        .year
+        .data_dir()
         .join(folder)
-        .join(format!("{day}-{part}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+        .join(format!("{}-{part}.txt", puzzle.day));
+    input::load(&filepath).expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Creates the constants `YEAR`, `DAY` and `PUZZLE` and sets up the input and runner for each part.
+/// The year and day must match the name of the binary, e.g. `solution!(2024, day = 1)` in `src/bin/2024-01.rs`.
 ///
-/// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// The optional, last parameter (1 or 2) allows you to only run a single part of the solution.
+///
+/// A shared parser can be declared with `parse = <fn>`, e.g. `solution!(2024, day = 12, parse = parse)`.
+/// It is run (and timed) once, and both parts receive a reference to its output instead of the input.
+///
+/// Expectations on the input can be declared with `input = [...]` after the day, e.g.
+/// `solution!(2024, day = 24, input = [Sections(2)])`. See [`input::Expect`] for the available ones.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+    ($year:expr, day = $day:expr, input = [$( $expect:expr ),* $(,)?] $(, $( $rest:tt )+ )?) => {
+        /// Expectations of the current day on its input, checked before solving.
+        const INPUT: &[$crate::template::input::Expect] = {
+            #[allow(unused_imports)]
+            use $crate::template::input::Expect::*;
+            &[$( $expect ),*]
+        };
+        $crate::solution!(@parts $year, day = $day $(, $( $rest )+ )?);
+    };
+    ($year:expr, day = $day:expr $(, $( $rest:tt )+ )?) => {
+        /// Expectations of the current day on its input, checked before solving.
+        const INPUT: &[$crate::template::input::Expect] = &[];
+        $crate::solution!(@parts $year, day = $day $(, $( $rest )+ )?);
+    };
+
+    (@parts $year:expr, day = $day:expr) => {
+        $crate::solution!(@impl $year, $day, [part_one, 1] [part_two, 2]);
+    };
+    (@parts $year:expr, day = $day:expr, 1) => {
+        $crate::solution!(@impl $year, $day, [part_one, 1]);
+    };
+    (@parts $year:expr, day = $day:expr, 2) => {
+        $crate::solution!(@impl $year, $day, [part_two, 2]);
     };
-    ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+    (@parts $year:expr, day = $day:expr, parse = $parser:expr) => {
+        $crate::solution!(@parse $year, $day, $parser, [part_one, 1] [part_two, 2]);
     };
-    ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+    (@parts $year:expr, day = $day:expr, parse = $parser:expr, 1) => {
+        $crate::solution!(@parse $year, $day, $parser, [part_one, 1]);
     };
+    (@parts $year:expr, day = $day:expr, parse = $parser:expr, 2) => {
+        $crate::solution!(@parse $year, $day, $parser, [part_two, 2]);
+    };
+
+    (@impl $year:expr, $day:expr, $( [$func:expr, $part:expr] )*) => {
+        $crate::solution!(@main $year, $day, |input, options| {
+            $crate::template::runner::DayResult {
+                parse: None,
+                parts: vec![$( $crate::template::runner::run_part($func, input, $part, options), )*],
+            }
+        });
+        $crate::solution!(@examples $year, $day, |input, part| {
+            $( if part == $part { return $crate::template::runner::PartOutput::answer(&$func(input)); } )*
+            panic!("part {part} is not solved by this day.");
+        });
+    };
+
+    (@parse $year:expr, $day:expr, $parser:expr, $( [$func:expr, $part:expr] )*) => {
+        $crate::solution!(@main $year, $day, |input, options| {
+            let (parsed, parse) = $crate::template::runner::run_parse($parser, input, options);
+            $crate::template::runner::DayResult {
+                parse: Some(parse),
+                parts: vec![$( $crate::template::runner::run_part($func, &parsed, $part, options), )*],
+            }
+        });
+        $crate::solution!(@examples $year, $day, |input, part| {
+            let parsed = $parser(input);
+            $( if part == $part { return $crate::template::runner::PartOutput::answer(&$func(&parsed)); } )*
+            panic!("part {part} is not solved by this day.");
+        });
+    };
+
+    // one test per example and part in `data/<year>/examples/answers.json`, generated by the build script.
+    (@examples $year:expr, $day:expr, |$input:ident, $part:ident| $body:block) => {
+        #[cfg(test)]
+        mod examples {
+            use super::*;
+
+            fn solve($input: &str, $part: u8) -> Result<Option<String>, String> $body
+
+            fn check(example: u8, part: u8, expected: &str) {
+                $crate::template::examples::check(&SOLUTION, example, part, expected, solve);
+            }
+
+            include!(concat!(env!("OUT_DIR"), "/examples/", $year, "-", $day, ".rs"));
+        }
+    };
+
+    (@main $year:expr, $day:expr, |$input:ident, $options:ident| $body:block) => {
+        /// The year of the current day's event.
+        const YEAR: $crate::template::Year = $crate::year!($year);
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
         /// The current day.
         const DAY: $crate::template::Day = $crate::day!($day);
 
+        /// The current puzzle, the day of the year's event.
+        const PUZZLE: $crate::template::Puzzle = $crate::template::Puzzle::new(YEAR, DAY);
+
         #[cfg(feature = "dhat-heap")]
         #[global_allocator]
         static ALLOC: dhat::Alloc = dhat::Alloc;
 
+        /// Entry point used to run this day, either from `main` or in-process from the main binary.
+        pub const SOLUTION: $crate::template::runner::Solution = $crate::template::runner::Solution {
+            puzzle: PUZZLE,
+            input: INPUT,
+            solve: {
+                fn solve(
+                    $input: &str,
+                    $options: &$crate::template::runner::RunOptions,
+                ) -> $crate::template::runner::DayResult $body
+                solve
+            },
+        };
+
         fn main() {
-            use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            if let Err(e) = $crate::template::runner::run_binary(&SOLUTION) {
+                eprintln!("Error: {e}");
+                std::process::exit(e.exit_code());
+            }
         }
     };
 }
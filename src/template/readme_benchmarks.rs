@@ -1,19 +1,31 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
-use std::{fs, io};
+use std::{fmt::Display, fs, io};
 
-use crate::template::timings::Timings;
-use crate::template::Day;
+use crate::template::runner::format_bytes;
+use crate::template::timings::{Timing, Timings};
+use crate::template::{config, Puzzle, Year};
 
-static MARKER: &str = "<!--- benchmarking table --->";
+/// Marker around the table of a year. Every year has its own table.
+pub(crate) fn marker(year: Year) -> String {
+    format!("<!--- {} {year} --->", config::get().benchmarks_marker)
+}
 
-#[allow(dead_code)]
 #[derive(Debug)]
 pub enum Error {
     Parser(String),
     IO(io::Error),
 }
 
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(message) => f.write_str(message),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
 impl From<std::io::Error> for Error {
     fn from(e: std::io::Error) -> Self {
         Error::IO(e)
@@ -25,24 +37,21 @@ pub struct TablePosition {
     pos_end: usize,
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+fn locate_table(readme: &str, year: Year) -> Result<TablePosition, Error> {
+    let marker = marker(year);
+    let matches: Vec<_> = readme.match_indices(&marker).collect();
 
     if matches.len() > 2 {
         return Err(Error::Parser(
-            "{}: too many occurences of marker in README.".into(),
+            "too many occurences of marker in README.".into(),
         ));
     }
 
-    let pos_start = matches
-        .first()
-        .map(|m| m.0)
-        .ok_or_else(|| Error::Parser("Could not find table start position.".into()))?;
+    let pos_start = matches.first().map(|m| m.0).ok_or_else(|| {
+        Error::Parser(format!(
+            "Could not find table start position, add `{marker}` to the README."
+        ))
+    })?;
 
     let pos_end = matches
         .last()
@@ -52,55 +61,102 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
+/// Memory columns of a day: the highest peak of its steps and the allocations of all steps.
+fn memory_cells(timing: &Timing) -> String {
+    let memory: Vec<_> = timing.memory().into_iter().flatten().collect();
+
+    if memory.is_empty() {
+        return " `-` | `-` |".into();
+    }
+
+    let peak_bytes = memory
+        .iter()
+        .map(|m| m.peak_bytes)
+        .max()
+        .unwrap_or_default();
+    let allocations: u64 = memory.iter().map(|m| m.allocations).sum();
+
+    format!(" `{}` | `{allocations}` |", format_bytes(peak_bytes))
+}
+
+fn construct_table(prefix: &str, year: Year, timings: Timings, total_millis: f64) -> String {
+    let header = format!("{prefix} {year} Benchmarks");
+
+    // memory columns are only shown once heap usage was measured with `time --memory`.
+    let has_memory = timings.data.iter().any(Timing::has_memory);
+
+    let (columns, alignment) = if has_memory {
+        (" Peak memory | Allocations |", " :---: | :---: |")
+    } else {
+        ("", "")
+    };
 
     let mut lines: Vec<String> = vec![
-        MARKER.into(),
+        marker(year),
         header,
         String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
+        format!("| Day | Parse | Part 1 | Part 2 |{columns}"),
+        format!("| :---: | :---: | :---: | :---:  |{alignment}"),
     ];
 
     for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
+        let path = Puzzle::new(year, timing.day).bin_path();
+        let memory = if has_memory {
+            memory_cells(&timing)
+        } else {
+            String::new()
+        };
+
         lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
+            "| [Day {}]({}) | `{}` | `{}` | `{}` |{}",
             timing.day.into_inner(),
             path,
+            timing.parse.unwrap_or_else(|| "-".into()),
             timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
+            timing.part_2.unwrap_or_else(|| "-".into()),
+            memory
         ));
     }
 
     lines.push(String::new());
     lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
+    lines.push(marker(year));
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+fn update_content(
+    s: &mut String,
+    year: Year,
+    timings: Timings,
+    total_millis: f64,
+) -> Result<(), Error> {
+    let positions = locate_table(s, year)?;
+    let table = construct_table("##", year, timings, total_millis);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
-    let path = "README.md";
+pub fn update(year: Year, mut timings: Timings) -> Result<(), Error> {
+    let path = &config::get().readme;
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+    // timings of days that are not part of the event, e.g. after shortening it, are left out.
+    timings.data.retain(|timing| year.has_day(timing.day));
     let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
+    update_content(&mut readme, year, timings, total_millis)?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
-#[cfg(feature = "test_lib")]
+#[cfg(all(test, feature = "test_lib"))]
 mod tests {
-    use super::{update_content, MARKER};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use super::{marker, update_content};
+    use crate::{
+        day, template::runner::Memory, template::timings::Timing, template::timings::Timings,
+        template::Year, year,
+    };
+
+    const YEAR: Year = year!(2024);
 
     fn get_mock_timings() -> Timings {
         Timings {
@@ -109,18 +165,48 @@ mod tests {
                     day: day!(1),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse: None,
+                    parse_stats: None,
+                    parse_memory: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    part_1_error: None,
+                    part_2_error: None,
+                    history: vec![],
                     total_nanos: 3e+10,
                 },
                 Timing {
                     day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse: Some("5ms".into()),
+                    parse_stats: None,
+                    parse_memory: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    part_1_error: None,
+                    part_2_error: None,
+                    history: vec![],
                     total_nanos: 7e+10,
                 },
                 Timing {
                     day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: Some("50ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse: None,
+                    parse_stats: None,
+                    parse_memory: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    part_1_error: None,
+                    part_2_error: None,
+                    history: vec![],
                     total_nanos: 9e+10,
                 },
             ],
@@ -131,53 +217,93 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, YEAR, get_mock_timings(), 190.0).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
-        let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        let marker = marker(YEAR);
+        let mut s = format!("{} {} {}", marker, marker, marker);
+        update_content(&mut s, YEAR, get_mock_timings(), 190.0).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
-        let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.contains("## Benchmarks"), true);
+        let marker = marker(YEAR);
+        let mut s = format!("foo\nbar\n{}{}\nbaz", marker, marker);
+        update_content(&mut s, YEAR, get_mock_timings(), 190.0).unwrap();
+        assert!(s.contains("## 2024 Benchmarks"));
     }
 
     #[test]
     fn updates_existing_benchmarks() {
-        let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
-        assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
+        let marker = marker(YEAR);
+        let mut s = format!("foo\nbar\n{}{}\nbaz", marker, marker);
+        update_content(&mut s, YEAR, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, YEAR, get_mock_timings(), 190.0).unwrap();
+        assert_eq!(s.matches(&marker).collect::<Vec<&str>>().len(), 2);
+        assert_eq!(
+            s.matches("## 2024 Benchmarks").collect::<Vec<&str>>().len(),
+            1
+        );
     }
 
     #[test]
     fn format_benchmarks() {
-        let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        let marker = marker(YEAR);
+        let mut s = format!("foo\nbar\n{}\n{}\nbaz", marker, marker);
+        update_content(&mut s, YEAR, get_mock_timings(), 190.0).unwrap();
         let expected = [
             "foo",
             "bar",
-            "<!--- benchmarking table --->",
-            "## Benchmarks",
+            "<!--- benchmarking table 2024 --->",
+            "## 2024 Benchmarks",
             "",
-            "| Day | Part 1 | Part 2 |",
-            "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| Day | Parse | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: | :---:  |",
+            "| [Day 1](./src/bin/2024-01.rs) | `-` | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/2024-02.rs) | `5ms` | `30ms` | `40ms` |",
+            "| [Day 4](./src/bin/2024-04.rs) | `-` | `40ms` | `50ms` |",
             "",
             "**Total: 190.00ms**",
-            "<!--- benchmarking table --->",
+            "<!--- benchmarking table 2024 --->",
             "baz",
         ]
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_memory_columns() {
+        let mut timings = get_mock_timings();
+        timings.data[1].parse_memory = Some(Memory {
+            peak_bytes: 4096,
+            total_bytes: 8192,
+            allocations: 3,
+        });
+        timings.data[1].part_1_memory = Some(Memory {
+            peak_bytes: 2048,
+            total_bytes: 2048,
+            allocations: 2,
+        });
+
+        let marker = marker(YEAR);
+        let mut s = format!("{}\n{}", marker, marker);
+        update_content(&mut s, YEAR, timings, 190.0).unwrap();
+
+        let lines: Vec<&str> = s.lines().collect();
+        assert_eq!(
+            lines[3],
+            "| Day | Parse | Part 1 | Part 2 | Peak memory | Allocations |"
+        );
+        assert_eq!(
+            lines[5],
+            "| [Day 1](./src/bin/2024-01.rs) | `-` | `10ms` | `20ms` | `-` | `-` |"
+        );
+        assert_eq!(
+            lines[6],
+            "| [Day 2](./src/bin/2024-02.rs) | `5ms` | `30ms` | `40ms` | `4.0 KiB` | `5` |"
+        );
+    }
 }
@@ -0,0 +1,298 @@
+/// Machine-readable record of a day's results.
+/// Solution binaries write it to the file named by `RECORD_FILE_ENV`, so `run_multi` does not have to parse their output.
+use std::{collections::HashMap, env, fs, path::Path, str::FromStr, time::Duration};
+use tinyjson::JsonValue;
+
+use crate::template::runner::{DayResult, ParseResult, PartResult};
+use crate::template::timings::{memory_from_json, memory_to_json, stats_from_json, stats_to_json};
+
+/// Env var that holds the path a solution binary writes its record to.
+pub const RECORD_FILE_ENV: &str = "AOC_RECORD_FILE";
+
+/// Write the record of a run if the env var is set. Failures are reported, but do not abort the run.
+pub fn write(result: &DayResult) {
+    let Ok(path) = env::var(RECORD_FILE_ENV) else {
+        return;
+    };
+
+    let written = JsonValue::from(result)
+        .stringify()
+        .map_err(|e| e.to_string())
+        .and_then(|json| fs::write(&path, json).map_err(|e| e.to_string()));
+
+    if let Err(e) = written {
+        eprintln!("Failed to write result record to \"{path}\": {e}");
+    }
+}
+
+/// Read and remove a record written by a solution binary.
+pub fn take(path: &Path) -> Result<DayResult, String> {
+    let json = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let _ = fs::remove_file(path);
+    DayResult::try_from(json)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[allow(clippy::cast_precision_loss)]
+fn nanos_to_json(duration: Duration) -> JsonValue {
+    JsonValue::Number(duration.as_nanos() as f64)
+}
+
+fn number(json: &HashMap<String, JsonValue>, key: &str) -> Result<f64, String> {
+    json.get(key)
+        .and_then(|v| v.get::<f64>().copied())
+        .ok_or(format!("Expected record `{key}` to be a number."))
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn duration(json: &HashMap<String, JsonValue>, key: &str) -> Result<Duration, String> {
+    number(json, key).map(|n| Duration::from_nanos(n as u64))
+}
+
+impl From<&DayResult> for JsonValue {
+    fn from(value: &DayResult) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "parse".into(),
+            match &value.parse {
+                Some(parse) => JsonValue::from(parse),
+                None => JsonValue::Null,
+            },
+        );
+
+        map.insert(
+            "parts".into(),
+            JsonValue::Array(value.parts.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for DayResult {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let parse = match json.get("parse") {
+            None | Some(JsonValue::Null) => None,
+            Some(v) => Some(ParseResult::try_from(v)?),
+        };
+
+        let parts = json
+            .get("parts")
+            .and_then(|v| v.get::<Vec<JsonValue>>())
+            .ok_or("expected `json.parts` to be an array.")?
+            .iter()
+            .map(PartResult::try_from)
+            .collect::<Result<_, _>>()?;
+
+        Ok(DayResult { parse, parts })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[allow(clippy::cast_precision_loss)]
+impl From<&ParseResult> for JsonValue {
+    fn from(value: &ParseResult) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("nanos".into(), nanos_to_json(value.duration));
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert("stats".into(), stats_to_json(value.stats));
+        map.insert("memory".into(), memory_to_json(value.memory));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for ParseResult {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected record.parse to be a JSON object.")?;
+
+        Ok(ParseResult {
+            duration: duration(json, "nanos")?,
+            samples: number(json, "samples")? as u128,
+            stats: json.get("stats").map_or(Ok(None), stats_from_json)?,
+            memory: json.get("memory").map_or(Ok(None), memory_from_json)?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[allow(clippy::cast_precision_loss)]
+impl From<&PartResult> for JsonValue {
+    fn from(value: &PartResult) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+
+        map.insert(
+            "answer".into(),
+            match &value.answer {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+
+        map.insert(
+            "error".into(),
+            value
+                .error
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+
+        map.insert("nanos".into(), nanos_to_json(value.duration));
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert("stats".into(), stats_to_json(value.stats));
+        map.insert("memory".into(), memory_to_json(value.memory));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartResult {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected record part to be a JSON object.")?;
+
+        let part = number(json, "part")? as u8;
+
+        let answer = json
+            .get("answer")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected record part answer to be null or string.")?;
+
+        let error = match json.get("error") {
+            None | Some(JsonValue::Null) => None,
+            Some(v) => Some(
+                v.get::<String>()
+                    .ok_or("Expected record part error to be null or string.")?
+                    .clone(),
+            ),
+        };
+
+        Ok(PartResult {
+            part,
+            answer: answer.cloned(),
+            error,
+            duration: duration(json, "nanos")?,
+            samples: number(json, "samples")? as u128,
+            stats: json.get("stats").map_or(Ok(None), stats_from_json)?,
+            memory: json.get("memory").map_or(Ok(None), memory_from_json)?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use std::time::Duration;
+    use tinyjson::JsonValue;
+
+    use crate::template::runner::{DayResult, Memory, ParseResult, PartResult, Stats};
+
+    fn get_mock_result() -> DayResult {
+        DayResult {
+            parse: Some(ParseResult {
+                duration: Duration::from_micros(3),
+                samples: 1,
+                stats: None,
+                memory: None,
+            }),
+            parts: vec![
+                PartResult {
+                    part: 1,
+                    answer: Some("Part 2: ✖ (1.0ms @ 5 samples)".into()),
+                    error: None,
+                    duration: Duration::from_nanos(74),
+                    samples: 100,
+                    stats: Some(Stats {
+                        median: Duration::from_nanos(74),
+                        mean: Duration::from_nanos(75),
+                        min: Duration::from_nanos(70),
+                        max: Duration::from_nanos(1000),
+                        std_dev: Duration::from_nanos(2),
+                        p95: Duration::from_nanos(80),
+                        samples: 100,
+                        outliers: 12,
+                    }),
+                    memory: Some(Memory {
+                        peak_bytes: 1024,
+                        total_bytes: 4096,
+                        allocations: 7,
+                    }),
+                },
+                PartResult {
+                    part: 2,
+                    answer: None,
+                    error: Some("no path found".into()),
+                    duration: Duration::from_millis(2),
+                    samples: 1,
+                    stats: None,
+                    memory: None,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn roundtrips_results() {
+        let result = get_mock_result();
+        let json = JsonValue::from(&result).stringify().unwrap();
+        let parsed = DayResult::try_from(json).unwrap();
+
+        assert_eq!(parsed.parse.unwrap().duration, Duration::from_micros(3));
+        assert_eq!(parsed.parts.len(), 2);
+
+        let part_1 = &parsed.parts[0];
+        assert_eq!(part_1.answer, result.parts[0].answer);
+        assert_eq!(part_1.error, None);
+        assert_eq!(part_1.duration, Duration::from_nanos(74));
+        assert_eq!(part_1.samples, 100);
+        assert_eq!(part_1.stats, result.parts[0].stats);
+        assert_eq!(part_1.memory, result.parts[0].memory);
+
+        let part_2 = &parsed.parts[1];
+        assert_eq!(part_2.answer, None);
+        assert_eq!(part_2.error.as_deref(), Some("no path found"));
+        assert_eq!(part_2.duration, Duration::from_millis(2));
+        assert_eq!(part_2.stats, None);
+        assert_eq!(part_2.memory, None);
+    }
+
+    #[test]
+    fn handles_missing_parse() {
+        let json = r#"{ "parse": null, "parts": [{ "part": 1, "answer": "42", "nanos": 5, "samples": 1, "stats": null }] }"#.to_string();
+        let parsed = DayResult::try_from(json).unwrap();
+        assert!(parsed.parse.is_none());
+        assert_eq!(parsed.parts[0].answer, Some("42".into()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_results() {
+        let json = r#"{ "parts": [{ "part": 1 }] }"#.to_string();
+        DayResult::try_from(json).unwrap();
+    }
+}
@@ -0,0 +1,337 @@
+/// Machine-readable output of the `solve`, `all` and `time` commands.
+/// Every part of a day is reported as one record, either as a JSON array or as tab-separated values.
+use std::{collections::HashMap, fmt, str::FromStr, time::Duration};
+use tinyjson::JsonValue;
+
+use crate::template::runner::{Failure, Outcome, Stats};
+use crate::template::timings::stats_to_json;
+use crate::template::Day;
+
+/// Format of the output printed by a run.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    /// Human-readable output, printed while the parts run.
+    #[default]
+    Text,
+    Json,
+    Tsv,
+}
+
+#[derive(Debug)]
+pub struct FormatError(String);
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "unknown format `{}`, expected text, json or tsv.",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for FormatError {}
+
+impl FromStr for Format {
+    type Err = FormatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "tsv" => Ok(Format::Tsv),
+            _ => Err(FormatError(s.into())),
+        }
+    }
+}
+
+impl Format {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Format::Text => "text",
+            Format::Json => "json",
+            Format::Tsv => "tsv",
+        }
+    }
+}
+
+/// Status of a single part.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    Solved,
+    Unsolved,
+    /// The part returned an error instead of an answer.
+    Error,
+    Panicked,
+    TimedOut,
+    /// The day exited with an error or its results could not be read.
+    Failed,
+}
+
+impl Status {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Status::Solved => "solved",
+            Status::Unsolved => "unsolved",
+            Status::Error => "error",
+            Status::Panicked => "panicked",
+            Status::TimedOut => "timed_out",
+            Status::Failed => "failed",
+        }
+    }
+}
+
+/// Result of a single day and part.
+#[derive(Clone, Debug)]
+pub struct Record {
+    pub day: Day,
+    pub part: u8,
+    pub status: Status,
+    pub answer: Option<String>,
+    /// The error returned by the part, if any.
+    pub error: Option<String>,
+    /// Duration of the single run or, if benched, the median of all samples.
+    pub duration: Option<Duration>,
+    pub samples: u128,
+    pub stats: Option<Stats>,
+}
+
+/// Build the records of both parts of a day.
+pub fn records(day: Day, outcome: &Outcome) -> Vec<Record> {
+    (1..=2)
+        .map(|part| {
+            let empty = Record {
+                day,
+                part,
+                status: Status::Unsolved,
+                answer: None,
+                error: None,
+                duration: None,
+                samples: 0,
+                stats: None,
+            };
+
+            match outcome {
+                Outcome::Finished(result) => match result.parts.iter().find(|r| r.part == part) {
+                    Some(result) => Record {
+                        status: if result.error.is_some() {
+                            Status::Error
+                        } else if result.answer.is_some() {
+                            Status::Solved
+                        } else {
+                            Status::Unsolved
+                        },
+                        answer: result.answer.clone(),
+                        error: result.error.clone(),
+                        duration: Some(result.duration),
+                        samples: result.samples,
+                        stats: result.stats,
+                        ..empty
+                    },
+                    None => empty,
+                },
+                Outcome::Failed(failure) => Record {
+                    status: match failure {
+                        Failure::Panicked => Status::Panicked,
+                        Failure::TimedOut(_) => Status::TimedOut,
+                        Failure::Exited(_) | Failure::Error(_) => Status::Failed,
+                    },
+                    ..empty
+                },
+                Outcome::Skipped => empty,
+            }
+        })
+        .collect()
+}
+
+/// Print records in a machine-readable format. Does nothing for `Format::Text`.
+pub fn print(records: &[Record], format: Format) {
+    match format {
+        Format::Text => {}
+        Format::Json => {
+            let json = JsonValue::Array(records.iter().map(JsonValue::from).collect());
+            println!("{}", json.stringify().unwrap());
+        }
+        Format::Tsv => {
+            println!("{}", TSV_HEADER.join("\t"));
+            for record in records {
+                println!("{}", to_tsv(record));
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+static TSV_HEADER: [&str; 13] = [
+    "day",
+    "part",
+    "status",
+    "answer",
+    "nanos",
+    "samples",
+    "mean_nanos",
+    "min_nanos",
+    "max_nanos",
+    "std_dev_nanos",
+    "p95_nanos",
+    "outliers",
+    "error",
+];
+
+fn to_tsv(record: &Record) -> String {
+    let nanos = |d: Option<Duration>| d.map(|d| d.as_nanos().to_string()).unwrap_or_default();
+    let stat = |f: fn(&Stats) -> Duration| nanos(record.stats.as_ref().map(f));
+
+    // answers may span multiple lines, e.g. for day 14.
+    let escape = |value: Option<&str>| {
+        value
+            .unwrap_or_default()
+            .replace('\\', "\\\\")
+            .replace('\t', "\\t")
+            .replace('\n', "\\n")
+    };
+
+    [
+        record.day.to_string(),
+        record.part.to_string(),
+        record.status.as_str().into(),
+        escape(record.answer.as_deref()),
+        nanos(record.duration),
+        record.samples.to_string(),
+        stat(|s| s.mean),
+        stat(|s| s.min),
+        stat(|s| s.max),
+        stat(|s| s.std_dev),
+        stat(|s| s.p95),
+        record
+            .stats
+            .map(|s| s.outliers.to_string())
+            .unwrap_or_default(),
+        escape(record.error.as_deref()),
+    ]
+    .join("\t")
+}
+
+#[allow(clippy::cast_precision_loss)]
+impl From<&Record> for JsonValue {
+    fn from(value: &Record) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert(
+            "status".into(),
+            JsonValue::String(value.status.as_str().into()),
+        );
+
+        map.insert(
+            "answer".into(),
+            match &value.answer {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+
+        map.insert(
+            "error".into(),
+            value
+                .error
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+
+        map.insert(
+            "nanos".into(),
+            match value.duration {
+                Some(d) => JsonValue::Number(d.as_nanos() as f64),
+                None => JsonValue::Null,
+            },
+        );
+
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert("stats".into(), stats_to_json(value.stats));
+
+        JsonValue::Object(map)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use std::time::Duration;
+
+    use super::{records, to_tsv, Status};
+    use crate::day;
+    use crate::template::runner::{DayResult, Failure, Outcome, PartResult};
+
+    fn get_mock_outcome() -> Outcome {
+        Outcome::Finished(DayResult {
+            parse: None,
+            parts: vec![
+                PartResult {
+                    part: 1,
+                    answer: Some("#.\n.#".into()),
+                    error: None,
+                    duration: Duration::from_nanos(42),
+                    samples: 1,
+                    stats: None,
+                    memory: None,
+                },
+                PartResult {
+                    part: 2,
+                    answer: None,
+                    error: None,
+                    duration: Duration::from_nanos(7),
+                    samples: 1,
+                    stats: None,
+                    memory: None,
+                },
+            ],
+        })
+    }
+
+    #[test]
+    fn builds_records() {
+        let finished = records(day!(3), &get_mock_outcome());
+        assert_eq!(finished.len(), 2);
+        assert_eq!(finished[0].status, Status::Solved);
+        assert_eq!(finished[1].status, Status::Unsolved);
+        assert_eq!(finished[1].duration, Some(Duration::from_nanos(7)));
+
+        let panicked = records(day!(3), &Outcome::Failed(Failure::Panicked));
+        assert_eq!(panicked[0].status, Status::Panicked);
+        assert_eq!(panicked[1].answer, None);
+
+        let timed_out = records(
+            day!(3),
+            &Outcome::Failed(Failure::TimedOut(Duration::from_secs(1))),
+        );
+        assert_eq!(timed_out[1].status, Status::TimedOut);
+
+        let exited = records(day!(3), &Outcome::Failed(Failure::Exited(Some(2))));
+        assert_eq!(exited[0].status, Status::Failed);
+
+        let Outcome::Finished(mut result) = get_mock_outcome() else {
+            unreachable!()
+        };
+        result.parts[1].error = Some("no path\tfound".into());
+        let errored = records(day!(3), &Outcome::Finished(result));
+        assert_eq!(errored[1].status, Status::Error);
+        assert_eq!(
+            to_tsv(&errored[1]),
+            "03\t2\terror\t\t7\t1\t\t\t\t\t\t\tno path\\tfound"
+        );
+    }
+
+    #[test]
+    fn formats_tsv() {
+        let records = records(day!(3), &get_mock_outcome());
+        assert_eq!(
+            to_tsv(&records[0]),
+            "03\t1\tsolved\t#.\\n.#\t42\t1\t\t\t\t\t\t\t"
+        );
+        assert_eq!(to_tsv(&records[1]), "03\t2\tunsolved\t\t7\t1\t\t\t\t\t\t\t");
+    }
+}
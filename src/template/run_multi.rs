@@ -1,56 +1,345 @@
-use std::{collections::HashSet, io};
+use std::{collections::HashSet, io, time::Duration};
 
-use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::template::runner::{
+    format_bytes, DayResult, Failure, Outcome, RunOptions, Solution, PARSE_LABEL,
+};
+use crate::template::{
+    report, Day, Format, Puzzle, Year, ANSI_BOLD, ANSI_ITALIC, ANSI_RED, ANSI_RESET,
+};
 
 use super::{
     all_days,
     timings::{Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+/// Answers printed by the solution of a single day.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DayAnswers {
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
 
-    let mut need_space = false;
+impl DayAnswers {
+    pub fn get(&self, part: u8) -> Option<&String> {
+        match part {
+            1 => self.part_1.as_ref(),
+            2 => self.part_2.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+/// How `run_multi` invokes the solution of a day.
+#[derive(Clone, Copy)]
+pub enum Mode<'a> {
+    /// Call the solutions linked into the main binary directly.
+    InProcess(&'a [Solution]),
+    /// Spawn `cargo run --bin <year>-<day>` for every day, isolating crashes from the runner.
+    Subprocess,
+}
+
+/// Cargo profile the solution binaries are built with in subprocess mode.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Profile {
+    Debug,
+    Release,
+    /// Optimized build with the `dhat-heap` feature, as used by `solve --dhat`.
+    Dhat,
+}
+
+impl Profile {
+    fn cargo_args(self) -> &'static [&'static str] {
+        match self {
+            Profile::Debug => &[],
+            Profile::Release => &["--release"],
+            Profile::Dhat => &["--profile", "dhat", "--features", "dhat-heap"],
+        }
+    }
+}
+
+/// Outcome of running a set of days.
+pub struct MultiRun {
+    /// Answers of every day that produced output.
+    pub answers: Vec<(Day, DayAnswers)>,
+    /// Benchmark times, only present for timed runs.
+    pub timings: Option<Timings>,
+}
+
+pub fn run_multi(
+    year: Year,
+    days_to_run: &HashSet<Day>,
+    mode: Mode,
+    is_release: bool,
+    options: &RunOptions,
+    jobs: usize,
+    timeout: Option<Duration>,
+) -> MultiRun {
+    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+    let mut answers: Vec<(Day, DayAnswers)> = Vec::with_capacity(days_to_run.len());
+    let is_text = options.format == Format::Text;
 
     // NOTE: use non-duplicate, sorted day values.
-    all_days()
+    let days: Vec<Day> = all_days(year)
         .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
+        .collect();
+
+    let run_day = |day: Day| {
+        if is_text {
+            outln!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+            outln!("------");
+        }
+
+        let outcome = match mode {
+            Mode::InProcess(solutions) => solutions
+                .iter()
+                .find(|solution| solution.puzzle == Puzzle::new(year, day))
+                .map_or(Outcome::Skipped, |solution| {
+                    in_process::run_solution(solution, options, timeout)
+                }),
+            Mode::Subprocess => {
+                let profile = if is_release {
+                    Profile::Release
+                } else {
+                    Profile::Debug
+                };
+
+                child_commands::run_solution(Puzzle::new(year, day), options, profile, timeout)
+                    .unwrap_or_else(|e| Outcome::Failed(Failure::Error(e.to_string())))
+            }
+        };
+
+        if is_text {
+            match &outcome {
+                Outcome::Finished(_) => {}
+                Outcome::Failed(failure) => outln!("{ANSI_RED}Failed:{ANSI_RESET} {failure}."),
+                Outcome::Skipped => outln!("Not solved."),
             }
-            need_space = true;
+        }
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+        outcome
+    };
+
+    let outcomes: Vec<Outcome> = if jobs > 1 {
+        parallel::run(&days, jobs, run_day)
+    } else {
+        days.iter()
+            .enumerate()
+            .map(|(i, day)| {
+                if is_text && i > 0 {
+                    println!();
+                }
+                run_day(*day)
+            })
+            .collect()
+    };
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+    let mut failures: Vec<(Day, Failure)> = vec![];
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
+    for (day, outcome) in days.iter().zip(&outcomes) {
+        match outcome {
+            Outcome::Finished(result) => {
+                answers.push((*day, parse_answers(result)));
+                timings.push(parse_exec_time(result, *day));
             }
-        });
+            Outcome::Failed(failure) => failures.push((*day, failure.clone())),
+            Outcome::Skipped => {}
+        }
+    }
 
-    if is_timed {
+    let timings = if options.is_timed {
         let timings = Timings { data: timings };
         let total_millis = timings.total_millis();
-        println!(
-            "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
-        );
+        if is_text {
+            println!(
+                "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
+            );
+        }
         Some(timings)
     } else {
         None
+    };
+
+    let records: Vec<_> = days
+        .iter()
+        .zip(&outcomes)
+        .flat_map(|(day, outcome)| report::records(*day, outcome))
+        .collect();
+    report::print(&records, options.format);
+    print_failures(&failures, is_text);
+
+    MultiRun { answers, timings }
+}
+
+/// Run days once under the `dhat-heap` feature and collect the heap usage of their steps.
+/// Days always run as their own binary, as the main binary is not built with the profiler.
+pub fn run_memory(
+    year: Year,
+    days_to_run: &HashSet<Day>,
+    timeout: Option<Duration>,
+    format: Format,
+) -> Vec<(Day, DayResult)> {
+    let is_text = format == Format::Text;
+    let options = RunOptions::default();
+
+    let mut results: Vec<(Day, DayResult)> = vec![];
+    let mut failures: Vec<(Day, Failure)> = vec![];
+
+    if is_text {
+        println!();
+        println!("{ANSI_BOLD}Memory{ANSI_RESET}");
+        println!("------");
+    }
+
+    for day in all_days(year).filter(|day| days_to_run.contains(day)) {
+        let puzzle = Puzzle::new(year, day);
+        let outcome = child_commands::run_solution(puzzle, &options, Profile::Dhat, timeout)
+            .unwrap_or_else(|e| Outcome::Failed(Failure::Error(e.to_string())));
+
+        match outcome {
+            Outcome::Finished(result) => {
+                if is_text {
+                    println!("{}", format_memory(day, &result));
+                }
+                results.push((day, result));
+            }
+            Outcome::Failed(failure) => failures.push((day, failure)),
+            Outcome::Skipped => {}
+        }
+    }
+
+    print_failures(&failures, is_text);
+    results
+}
+
+/// Summarize the heap usage of a day's steps on one line.
+fn format_memory(day: Day, result: &DayResult) -> String {
+    let parse = result
+        .parse
+        .as_ref()
+        .and_then(|parse| parse.memory)
+        .map(|memory| (PARSE_LABEL.to_string(), memory));
+
+    let parts = result.parts.iter().filter_map(|part| {
+        part.memory
+            .map(|memory| (format!("Part {}", part.part), memory))
+    });
+
+    let steps: Vec<String> = parse
+        .into_iter()
+        .chain(parts)
+        .map(|(label, memory)| {
+            format!(
+                "{label} {} peak, {} allocations",
+                format_bytes(memory.peak_bytes),
+                memory.allocations
+            )
+        })
+        .collect();
+
+    format!("Day {day}: {}", steps.join(" · "))
+}
+
+/// Print a summary of failed days. Printed to stderr for machine-readable formats to keep stdout parseable.
+fn print_failures(failures: &[(Day, Failure)], is_text: bool) {
+    if failures.is_empty() {
+        return;
+    }
+
+    let mut lines = vec![format!(
+        "\n{ANSI_BOLD}{ANSI_RED}{} day(s) failed:{ANSI_RESET}",
+        failures.len()
+    )];
+
+    lines.extend(
+        failures
+            .iter()
+            .map(|(day, failure)| format!("  Day {day}: {failure}")),
+    );
+
+    if is_text {
+        println!("{}", lines.join("\n"));
+    } else {
+        eprintln!("{}", lines.join("\n"));
+    }
+}
+
+/// Recover the answers of a day from its results.
+pub fn parse_answers(results: &DayResult) -> DayAnswers {
+    let answer = |part| {
+        results
+            .parts
+            .iter()
+            .find(|r| r.part == part)
+            .and_then(|r| r.answer.clone())
+    };
+
+    DayAnswers {
+        part_1: answer(1),
+        part_2: answer(2),
+    }
+}
+
+/// Recover the benchmark times of a day from its results.
+pub fn parse_exec_time(results: &DayResult, day: Day) -> Timing {
+    let mut timings = Timing {
+        day,
+        parse: None,
+        part_1: None,
+        part_2: None,
+        parse_stats: None,
+        parse_memory: None,
+        part_1_memory: None,
+        part_2_memory: None,
+        part_1_error: None,
+        part_2_error: None,
+        part_1_stats: None,
+        part_2_stats: None,
+        total_nanos: 0_f64,
+        history: vec![],
+    };
+
+    // only benched steps are recorded.
+    if let Some(parse) = results.parse.as_ref().filter(|r| r.samples > 1) {
+        timings.parse = Some(format!("{:.1?}", parse.duration));
+        timings.parse_stats = parse.stats;
+
+        #[allow(clippy::cast_precision_loss)]
+        {
+            timings.total_nanos += parse.duration.as_nanos() as f64;
+        }
+    }
+
+    for result in results.parts.iter().filter(|r| r.samples > 1) {
+        let timing_str = format!("{:.1?}", result.duration);
+
+        match result.part {
+            1 => {
+                timings.part_1 = Some(timing_str);
+                timings.part_1_stats = result.stats;
+                timings.part_1_error.clone_from(&result.error);
+            }
+            _ => {
+                timings.part_2 = Some(timing_str);
+                timings.part_2_stats = result.stats;
+                timings.part_2_error.clone_from(&result.error);
+            }
+        }
+
+        #[allow(clippy::cast_precision_loss)]
+        {
+            timings.total_nanos += result.duration.as_nanos() as f64;
+        }
     }
+
+    timings
 }
 
-#[allow(dead_code)]
 #[derive(Debug)]
 pub enum Error {
-    BrokenPipe,
     IO(io::Error),
+    /// `cargo build` failed or did not report the day's executable.
+    Build,
+    Record(String),
 }
 
 impl From<std::io::Error> for Error {
@@ -59,199 +348,360 @@ impl From<std::io::Error> for Error {
     }
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::IO(e) => write!(f, "could not run the solution: {e}"),
+            Error::Build => write!(f, "could not be built"),
+            Error::Record(e) => write!(f, "could not read the result record: {e}"),
+        }
+    }
+}
+
+/// Runs days on a pool of worker threads.
+/// The output of every day is collected and printed as one block, in day order.
+mod parallel {
+    use crate::template::runner::{capture, Failure, Outcome};
+    use crate::template::Day;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::mpsc;
+    use std::thread;
+
+    pub fn run(days: &[Day], jobs: usize, run_day: impl Fn(Day) -> Outcome + Sync) -> Vec<Outcome> {
+        let next = AtomicUsize::new(0);
+        let (sender, receiver) = mpsc::channel();
+
+        thread::scope(|scope| {
+            for _ in 0..jobs.min(days.len()) {
+                let sender = sender.clone();
+                let (next, run_day) = (&next, &run_day);
+
+                scope.spawn(move || loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(day) = days.get(i) else {
+                        break;
+                    };
+                    let (outcome, output) = capture(|| run_day(*day));
+                    if sender.send((i, outcome, output)).is_err() {
+                        break;
+                    }
+                });
+            }
+
+            // all senders are owned by workers, so the receiver ends with the last of them.
+            drop(sender);
+
+            let mut finished: Vec<Option<(Outcome, String)>> = days.iter().map(|_| None).collect();
+            let mut printed = 0;
+
+            for (i, outcome, output) in receiver {
+                finished[i] = Some((outcome, output));
+
+                // print every block that is next in order.
+                while let Some(Some((_, output))) = finished.get(printed) {
+                    if printed > 0 {
+                        println!();
+                    }
+                    print!("{output}");
+                    printed += 1;
+                }
+            }
+
+            finished
+                .into_iter()
+                .map(|day| day.map_or(Outcome::Failed(Failure::Panicked), |(outcome, _)| outcome))
+                .collect()
+        })
+    }
+}
+
+/// Solutions linked into the main binary can be called directly.
+/// This module encapsulates calling them.
+pub mod in_process {
+    use crate::template::input;
+    use crate::template::runner::{capture, Failure, Outcome, RunOptions, Solution};
+    use std::sync::mpsc::{self, RecvTimeoutError};
+    use std::time::Duration;
+    use std::{panic, thread};
+
+    /// Run the solution for a given day.
+    pub fn run_solution(
+        solution: &Solution,
+        options: &RunOptions,
+        timeout: Option<Duration>,
+    ) -> Outcome {
+        let path = solution.puzzle.input_path();
+
+        let input = match input::load(&path) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Could not read input file \"{}\": {e}", path.display());
+                return Outcome::Skipped;
+            }
+        };
+
+        if let Err(e) = solution.check_input(&input) {
+            return Outcome::Failed(Failure::Error(e));
+        }
+
+        let options = RunOptions {
+            submit: None,
+            ..options.clone()
+        };
+
+        let Some(timeout) = timeout else {
+            // the panic message is printed by the default hook.
+            return match panic::catch_unwind(|| (solution.solve)(&input, &options)) {
+                Ok(result) => Outcome::Finished(result),
+                Err(_) => Outcome::Failed(Failure::Panicked),
+            };
+        };
+
+        // threads cannot be stopped, so a day that times out is left running in the background.
+        // its output is collected, so it cannot garble the output of the days that run after it.
+        let solve = solution.solve;
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            let _ = sender.send(capture(|| solve(&input, &options)));
+        });
+
+        match receiver.recv_timeout(timeout) {
+            Ok((result, output)) => {
+                out!("{output}");
+                Outcome::Finished(result)
+            }
+            // the sender is dropped without sending if the solution panicked.
+            Err(RecvTimeoutError::Disconnected) => Outcome::Failed(Failure::Panicked),
+            Err(RecvTimeoutError::Timeout) => Outcome::Failed(Failure::TimedOut(timeout)),
+        }
+    }
 }
 
 /// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// This module encapsulates building and invoking these binaries and reading the record of their results.
 pub mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::template::Day;
+    use super::{Error, Profile};
+    use crate::template::record::{self, RECORD_FILE_ENV};
+    use crate::template::runner::{is_capturing, Failure, Outcome, RunOptions};
+    use crate::template::{crypt, Format, Puzzle};
     use std::{
-        io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
+        collections::HashMap,
+        env, fs,
+        io::{self, BufRead, BufReader, Read},
+        path::{Path, PathBuf},
+        process::{self, Child, ExitStatus, Stdio},
+        str::FromStr,
         thread,
+        time::{Duration, Instant},
     };
-
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    use tinyjson::JsonValue;
+
+    /// Exit code of a Rust program that panicked.
+    const PANIC_EXIT_CODE: i32 = 101;
+
+    /// Run the solution bin for a given puzzle.
+    pub fn run_solution(
+        puzzle: Puzzle,
+        options: &RunOptions,
+        profile: Profile,
+        timeout: Option<Duration>,
+    ) -> Result<Outcome, Error> {
         // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+        if !Path::new(&puzzle.bin_path()).exists() {
+            return Ok(Outcome::Skipped);
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        // mirror `in_process`: a missing input skips the day instead of panicking in the child.
+        // an input that only exists encrypted is decrypted by the child, see `input::load`.
+        let input_path = puzzle.input_path();
+        let is_encrypted = crypt::encrypted_path(&input_path).is_some_and(|path| path.is_file());
+        if !input_path.exists() && !is_encrypted {
+            eprintln!("Could not read input file \"{}\".", input_path.display());
+            return Ok(Outcome::Skipped);
+        }
+
+        // the binary is built up front and run directly, so the timeout does not include
+        // compile times and stopping the day does not leave a process behind `cargo run`.
+        let executable = build(puzzle, profile)?;
+
+        let budget = options.budget.as_millis().to_string();
+        let mut args = vec![];
 
-        if is_release {
-            args.push("--release");
+        if options.is_timed {
+            // mirror `--time` and `--budget` flags to child invocations.
+            args.extend(["--time", "--budget", &budget]);
         }
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+        if options.format != Format::Text {
+            args.extend(["--format", options.format.as_str()]);
         }
 
-        // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        // the child writes its results to this file, its output is forwarded as-is.
+        // machine-readable output is printed for all days at once, so the child's is discarded.
+        let record_path = env::temp_dir().join(format!("aoc-{}-{puzzle}.json", process::id()));
 
-        let mut cmd = Command::new("cargo")
-            .args(&args)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()?;
-
-        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
-        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
+        // heap profiling runs are only read for their record. dhat reports every step on
+        // stderr, which is only forwarded if the day fails.
+        let is_profiling = profile == Profile::Dhat;
 
-        let mut output = vec![];
+        let mut cmd = process::Command::new(executable);
+        cmd.args(&args).env(RECORD_FILE_ENV, &record_path);
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
-            });
-        });
+        if options.format != Format::Text || is_profiling {
+            cmd.stdout(Stdio::null());
+        } else if is_capturing() {
+            cmd.stdout(Stdio::piped());
+        }
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+        if is_profiling {
+            cmd.stderr(Stdio::piped());
         }
 
-        thread.join().unwrap();
-        cmd.wait()?;
+        let mut child = cmd.spawn()?;
 
-        Ok(output)
-    }
+        // read piped output while waiting, so a full pipe cannot block the child.
+        let stdout = child.stdout.take().map(read_to_end);
+        let stderr = child.stderr.take().map(read_to_end);
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
-        let mut timings = super::Timing {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
+        let status = wait(&mut child, timeout)?;
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+        if let Some(output) = stdout.and_then(|reader| reader.join().ok()) {
+            out!("{}", String::from_utf8_lossy(&output));
+        }
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
+        if let Some(output) = stderr.and_then(|reader| reader.join().ok()) {
+            if !status.is_some_and(|status| status.success()) {
+                eprint!("{}", String::from_utf8_lossy(&output));
+            }
+        }
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
+        let Some(status) = status else {
+            let _ = fs::remove_file(&record_path);
+            return Ok(Outcome::Failed(Failure::TimedOut(
+                timeout.unwrap_or_default(),
+            )));
+        };
 
-                timings.total_nanos += nanos;
-            });
+        if !status.success() {
+            let _ = fs::remove_file(&record_path);
+            return Ok(Outcome::Failed(match status.code() {
+                Some(PANIC_EXIT_CODE) => Failure::Panicked,
+                code => Failure::Exited(code),
+            }));
+        }
 
-        timings
+        record::take(&record_path)
+            .map(Outcome::Finished)
+            .map_err(Error::Record)
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    /// Read a pipe of the child on a separate thread.
+    fn read_to_end(mut pipe: impl Read + Send + 'static) -> thread::JoinHandle<Vec<u8>> {
+        thread::spawn(move || {
+            let mut output = vec![];
+            let _ = pipe.read_to_end(&mut output);
+            output
+        })
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+    /// Wait for the child to exit. Kills it and returns `None` if it runs longer than `timeout`.
+    fn wait(child: &mut Child, timeout: Option<Duration>) -> io::Result<Option<ExitStatus>> {
+        let Some(timeout) = timeout else {
+            return child.wait().map(Some);
+        };
+
+        let start = Instant::now();
+
+        loop {
+            if let Some(status) = child.try_wait()? {
+                return Ok(Some(status));
+            }
+
+            if start.elapsed() >= timeout {
+                child.kill()?;
+                child.wait()?;
+                return Ok(None);
+            }
+
+            thread::sleep(Duration::from_millis(10));
+        }
     }
 
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
+    /// Build the binary of a puzzle and return the path of its executable.
+    fn build(puzzle: Puzzle, profile: Profile) -> Result<PathBuf, Error> {
+        let bin_name = puzzle.to_string();
+        let mut args = vec![
+            "build",
+            "--quiet",
+            "--bin",
+            &bin_name,
+            "--message-format=json-render-diagnostics",
+        ];
+
+        args.extend(profile.cargo_args());
+
+        // compiler errors are rendered to stderr, stdout only carries JSON messages.
+        let mut cmd = process::Command::new("cargo")
+            .args(&args)
+            .stdout(Stdio::piped())
+            .spawn()?;
+
+        let executable = cmd
+            .stdout
+            .take()
+            .map(BufReader::new)
+            .into_iter()
+            .flat_map(BufRead::lines)
+            .map_while(Result::ok)
+            .filter_map(|line| parse_executable(&line, &bin_name))
+            .last();
+
+        if !cmd.wait()?.success() {
+            return Err(Error::Build);
+        }
+
+        executable.ok_or(Error::Build)
     }
 
-    #[cfg(feature = "test_lib")]
-    mod tests {
-        use super::parse_exec_time;
+    /// Read the executable from a cargo `compiler-artifact` message for the given bin.
+    fn parse_executable(line: &str, name: &str) -> Option<PathBuf> {
+        let json = JsonValue::from_str(line).ok()?;
+        let json = json.get::<HashMap<String, JsonValue>>()?;
+        let string = |value: Option<&JsonValue>| value.and_then(|v| v.get::<String>()).cloned();
 
-        use crate::day;
+        let target = json
+            .get("target")
+            .and_then(|target| target.get::<HashMap<String, JsonValue>>())?;
 
-        #[test]
-        fn parses_execution_times() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+        if string(json.get("reason"))? != "compiler-artifact" || string(target.get("name"))? != name
+        {
+            return None;
         }
 
+        string(json.get("executable")).map(PathBuf::from)
+    }
+
+    #[cfg(all(test, feature = "test_lib"))]
+    mod tests {
+        use super::parse_executable;
+        use std::path::PathBuf;
+
         #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
+        fn parses_executable() {
+            let line = r#"{"reason":"compiler-artifact","target":{"name":"2024-01","kind":["bin"]},"executable":"/aoc/target/release/2024-01","fresh":true}"#;
+            assert_eq!(
+                parse_executable(line, "2024-01"),
+                Some(PathBuf::from("/aoc/target/release/2024-01"))
             );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
-        }
+            assert_eq!(parse_executable(line, "2024-02"), None);
 
-        #[test]
-        fn parses_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
+            let lib = r#"{"reason":"compiler-artifact","target":{"name":"advent_of_code","kind":["lib"]},"executable":null}"#;
+            assert_eq!(parse_executable(lib, "advent_of_code"), None);
+            assert_eq!(
+                parse_executable(r#"{"reason":"build-finished","success":true}"#, "01"),
+                None
             );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
         }
     }
 }
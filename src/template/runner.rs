@@ -1,65 +1,591 @@
 /// Encapsulates code that interacts with solution functions.
+use std::cell::RefCell;
 use std::fmt::Display;
 use std::hint::black_box;
-use std::io::{stdout, Write};
-use std::process::Output;
+use std::io::{self, stdout, Read, Write};
+use std::path::PathBuf;
+use std::str::FromStr;
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{cmp, env, panic};
 
-use crate::template::ANSI_BOLD;
-use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
+use crate::template::answers::{Answers, Check};
+use crate::template::aoc_client::Hint;
+use crate::template::examples::{self, ExampleAnswers};
+use crate::template::input::{self, Expect};
+use crate::template::report::Format;
+use crate::template::{aoc_client, config, record, report, Error, Puzzle, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{ANSI_BOLD, ANSI_GREEN, ANSI_RED};
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
-    let part_str = format!("Part {part}");
+thread_local! {
+    /// Output of the current thread while inside `capture`.
+    static CAPTURED: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Run `func`, collecting the output the runner prints on the current thread instead of printing it.
+pub fn capture<T>(func: impl FnOnce() -> T) -> (T, String) {
+    CAPTURED.set(Some(String::new()));
+    let result = func();
+    (result, CAPTURED.take().unwrap_or_default())
+}
+
+/// Whether output of the current thread is collected by `capture`.
+pub fn is_capturing() -> bool {
+    CAPTURED.with_borrow(Option::is_some)
+}
+
+/// Print runner output, see the `out!` and `outln!` macros.
+pub(crate) fn write_output(s: &str) {
+    let is_captured = CAPTURED
+        .with_borrow_mut(|buffer| buffer.as_mut().map(|buffer| buffer.push_str(s)).is_some());
+
+    if !is_captured {
+        print!("{s}");
+    }
+}
+
+/// Default time budget for benching a single part, see `bench.budget` in `aoc.toml`.
+pub const DEFAULT_BUDGET: Duration = Duration::from_secs(1);
+
+/// Where a solution binary reads its input from.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// The day's own input in `data/<year>/inputs`.
+    #[default]
+    Default,
+    File(PathBuf),
+    Stdin,
+    /// A single example of the day in `data/<year>/examples`, or all of them if `None`.
+    Examples(Option<u8>),
+}
+
+impl InputSource {
+    /// Read the input of a puzzle from this source, normalised by `input::load`.
+    pub fn read(&self, puzzle: Puzzle) -> io::Result<String> {
+        match self {
+            InputSource::Default => input::load(&puzzle.input_path()),
+            InputSource::File(path) => input::load(path),
+            InputSource::Stdin => {
+                let mut raw = String::new();
+                io::stdin().read_to_string(&mut raw)?;
+                Ok(input::prepare(&raw, "from stdin"))
+            }
+            InputSource::Examples(example) => {
+                input::load(&examples::get_path(puzzle, example.unwrap_or(1)))
+            }
+        }
+    }
+
+    /// Label printed next to the results, `None` for the day's own input.
+    pub fn label(&self) -> Option<String> {
+        match self {
+            InputSource::Default => None,
+            InputSource::File(path) => Some(path.display().to_string()),
+            InputSource::Stdin => Some("stdin".into()),
+            InputSource::Examples(example) => Some(format!("example {}", example.unwrap_or(1))),
+        }
+    }
+}
+
+/// Options that control how a solution part is run.
+#[derive(Clone, Debug)]
+pub struct RunOptions {
+    /// Bench the part instead of running it once.
+    pub is_timed: bool,
+    /// Approximate time spent benching a part.
+    pub budget: Duration,
+    /// Part to submit, if any.
+    pub submit: Option<u8>,
+    /// Output format. Anything but `Format::Text` silences the output printed while running.
+    pub format: Format,
+    /// Input the day is run against.
+    pub input: InputSource,
+}
+
+impl Default for RunOptions {
+    fn default() -> Self {
+        Self {
+            is_timed: false,
+            budget: config::get().budget,
+            submit: None,
+            format: Format::Text,
+            input: InputSource::Default,
+        }
+    }
+}
+
+/// Parse the value following a flag, `None` if the flag is not passed.
+fn flag<T: FromStr>(args: &[String], flag: &str, format: &str) -> Result<Option<T>, Error> {
+    args.iter()
+        .position(|x| x == flag)
+        .map(|index| {
+            args.get(index + 1)
+                .and_then(|value| value.parse().ok())
+                .ok_or_else(|| {
+                    Error::Parse(format!("Unexpected command-line input. Format: {format}"))
+                })
+        })
+        .transpose()
+}
+
+impl RunOptions {
+    /// Parse the arguments passed to a solution binary.
+    pub fn from_args() -> Result<Self, Error> {
+        let args: Vec<String> = env::args().collect();
+
+        let submit = flag(&args, "--submit", "cargo solve 1 --submit 1")?;
+
+        let budget = flag(&args, "--budget", "--budget <milliseconds>")?
+            .map_or(config::get().budget, Duration::from_millis);
+
+        let format = flag(&args, "--format", "--format <text|json|tsv>")?.unwrap_or(Format::Text);
+
+        let input = match flag::<PathBuf>(&args, "--input", "--input <path>")? {
+            Some(path) => InputSource::File(path),
+            None if args.iter().any(|x| x == "--stdin") => InputSource::Stdin,
+            None => match args.iter().position(|x| x == "--example") {
+                // the number of the example is optional, all examples are run without it.
+                Some(index) => {
+                    InputSource::Examples(args.get(index + 1).and_then(|x| x.parse().ok()))
+                }
+                None => InputSource::Default,
+            },
+        };
+
+        // results of several examples cannot be told apart in machine-readable output.
+        if matches!(input, InputSource::Examples(_)) && format != Format::Text {
+            return Err(Error::Parse(
+                "Examples can only be run with text output.".into(),
+            ));
+        }
+
+        // answers are only submitted for the day's own input.
+        if submit.is_some() && input != InputSource::Default {
+            return Err(Error::Parse(
+                "Answers can only be submitted for the input in `data/<year>/inputs`.".into(),
+            ));
+        }
+
+        Ok(Self {
+            is_timed: args.iter().any(|x| x == "--time"),
+            budget,
+            submit,
+            format,
+            input,
+        })
+    }
+}
+
+/// Statistics over the samples of a benched part, after outliers have been removed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    pub median: Duration,
+    pub mean: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    pub std_dev: Duration,
+    pub p95: Duration,
+    /// Number of samples, including outliers.
+    pub samples: u128,
+    /// Number of samples rejected as outliers.
+    pub outliers: u128,
+}
+
+/// Heap usage of a single run, measured by dhat under the `dhat-heap` feature.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Memory {
+    /// Bytes allocated at the peak of heap usage.
+    pub peak_bytes: u64,
+    /// Bytes allocated over the whole run.
+    pub total_bytes: u64,
+    /// Number of allocations over the whole run.
+    pub allocations: u64,
+}
+
+/// Return type of a solution part. Parts return `Option<T>` if they may not be solved yet, or
+/// `Result<T, E>` to explain why they failed, e.g. that no path through a grid was found.
+pub trait PartOutput {
+    /// The answer of the part, `Ok(None)` if it is not solved, or the error it returned.
+    fn answer(&self) -> Result<Option<String>, String>;
+}
+
+impl<T: Display> PartOutput for Option<T> {
+    fn answer(&self) -> Result<Option<String>, String> {
+        Ok(self.as_ref().map(ToString::to_string))
+    }
+}
+
+impl<T: Display, E: Display> PartOutput for Result<T, E> {
+    fn answer(&self) -> Result<Option<String>, String> {
+        match self {
+            Ok(answer) => Ok(Some(answer.to_string())),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+}
+
+/// Outcome of running a single solution part.
+#[derive(Clone, Debug)]
+pub struct PartResult {
+    pub part: u8,
+    pub answer: Option<String>,
+    /// Only present if the part returned an error instead of an answer.
+    pub error: Option<String>,
+    /// Duration of the single run or, if benched, the median of all samples.
+    pub duration: Duration,
+    pub samples: u128,
+    /// Only present if the part was benched.
+    pub stats: Option<Stats>,
+    /// Only present if the part ran under the `dhat-heap` feature.
+    pub memory: Option<Memory>,
+}
+
+/// Outcome of running the shared parser of a day.
+#[derive(Clone, Debug)]
+pub struct ParseResult {
+    /// Duration of the single run or, if benched, the median of all samples.
+    pub duration: Duration,
+    pub samples: u128,
+    /// Only present if the parser was benched.
+    pub stats: Option<Stats>,
+    /// Only present if the parser ran under the `dhat-heap` feature.
+    pub memory: Option<Memory>,
+}
+
+/// Outcome of running all parts of a day.
+#[derive(Clone, Debug, Default)]
+pub struct DayResult {
+    /// Only present if the day declares a shared parser.
+    pub parse: Option<ParseResult>,
+    pub parts: Vec<PartResult>,
+}
+
+/// How running a day ended.
+#[derive(Clone, Debug)]
+pub enum Outcome {
+    Finished(DayResult),
+    Failed(Failure),
+    /// The day has no solution or input yet.
+    Skipped,
+}
+
+/// Why a day did not finish.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Failure {
+    Panicked,
+    /// The day was stopped after running for the given time.
+    TimedOut(Duration),
+    /// The solution binary exited with a non-zero status other than a panic's. `None` if it was killed by a signal.
+    Exited(Option<i32>),
+    /// The day could not be run or its results could not be read.
+    Error(String),
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Failure::Panicked => write!(f, "panicked"),
+            Failure::TimedOut(timeout) => write!(f, "timed out after {timeout:.1?}"),
+            Failure::Exited(Some(code)) => write!(f, "exited with status {code}"),
+            Failure::Exited(None) => write!(f, "was terminated by a signal"),
+            Failure::Error(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+/// Entry point to a day's solution, generated by the `solution!` macro.
+/// Allows running days in-process instead of spawning their binaries.
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub puzzle: Puzzle,
+    /// Expectations on the input, checked before solving.
+    pub input: &'static [Expect],
+    pub solve: fn(&str, &RunOptions) -> DayResult,
+}
+
+impl Solution {
+    /// Check an input against the expectations of the day.
+    pub fn check_input(&self, input: &str) -> Result<(), String> {
+        input::check(input, self.input)
+            .map_err(|e| format!("the input does not fit day {}, {e}.", self.puzzle.day))
+    }
+}
+
+/// Run a day as its own binary, reading the input from `data/<year>/inputs` unless `--input` or `--stdin` is passed.
+pub fn run_binary(solution: &Solution) -> Result<(), Error> {
+    config::init()?;
+    let options = RunOptions::from_args()?;
+
+    if let InputSource::Examples(example) = options.input {
+        return run_examples(solution, &options, example);
+    }
+
+    let label = options
+        .input
+        .label()
+        .unwrap_or_else(|| solution.puzzle.input_path().display().to_string());
+    let input = options
+        .input
+        .read(solution.puzzle)
+        .map_err(Error::io(&format!("Could not read input from {label}")))?;
+    solution.check_input(&input).map_err(Error::Input)?;
+
+    // the panic message is printed by the default hook.
+    let outcome = match panic::catch_unwind(|| (solution.solve)(&input, &options)) {
+        Ok(result) => {
+            record::write(&result);
+            Outcome::Finished(result)
+        }
+        Err(_) => Outcome::Failed(Failure::Panicked),
+    };
+
+    report::print(
+        &report::records(solution.puzzle.day, &outcome),
+        options.format,
+    );
+
+    match outcome {
+        Outcome::Finished(result) => match options.submit {
+            Some(part) => submit_part(&result, solution.puzzle, part),
+            None => Ok(()),
+        },
+        Outcome::Failed(_) => Err(Error::Panicked),
+        Outcome::Skipped => Ok(()),
+    }
+}
+
+/// Run a day against its examples and check the answers against the expected ones.
+/// Fails if an example panicked or an answer does not match.
+fn run_examples(
+    solution: &Solution,
+    options: &RunOptions,
+    example: Option<u8>,
+) -> Result<(), Error> {
+    let puzzle = solution.puzzle;
+    let examples = example.map_or_else(|| examples::find(puzzle), |example| vec![example]);
+
+    if examples.is_empty() {
+        return Err(Error::Io(format!(
+            "No examples found for day {} in `data/{}/examples`.",
+            puzzle.day, puzzle.year
+        )));
+    }
+
+    let expected = ExampleAnswers::read_from_file(puzzle.year)?;
+    let mut failures = 0;
+
+    for (i, example) in examples.into_iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+
+        let options = RunOptions {
+            input: InputSource::Examples(Some(example)),
+            ..options.clone()
+        };
+
+        let input = match options.input.read(puzzle) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Could not read example {example}: {e}");
+                failures += 1;
+                continue;
+            }
+        };
+
+        if let Err(e) = solution.check_input(&input) {
+            eprintln!("Skipped example {example}, {e}");
+            failures += 1;
+            continue;
+        }
+
+        // the panic message is printed by the default hook.
+        let Ok(result) = panic::catch_unwind(|| (solution.solve)(&input, &options)) else {
+            failures += 1;
+            continue;
+        };
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+        let checks: Vec<String> = result
+            .parts
+            .iter()
+            .filter_map(|part| {
+                let answer = expected.get(puzzle.day, example, part.part)?;
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+                if part.answer.as_ref() == Some(answer) {
+                    Some(format!("Part {} {ANSI_GREEN}pass{ANSI_RESET}", part.part))
+                } else {
+                    failures += 1;
+                    Some(format!(
+                        "Part {} {ANSI_RED}fail{ANSI_RESET} (expected `{answer}`)",
+                        part.part
+                    ))
+                }
+            })
+            .collect();
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+        if checks.is_empty() {
+            println!("Expected: {ANSI_ITALIC}no answers recorded{ANSI_RESET}");
+        } else {
+            println!("Expected: {}", checks.join(" · "));
+        }
     }
+
+    if failures > 0 {
+        return Err(Error::Check(format!(
+            "{failures} example part(s) failed or did not match their expected answer."
+        )));
+    }
+
+    Ok(())
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+/// Run the shared parser of a day once, so its time is reported apart from the parts.
+pub fn run_parse<'a, P>(
+    func: impl Fn(&'a str) -> P,
+    input: &'a str,
+    options: &RunOptions,
+) -> (P, ParseResult) {
+    let is_quiet = options.format != Format::Text;
+    let label = step_label(PARSE_LABEL, options);
+
+    let (parsed, duration, stats, memory) = run_timed(func, input, options, |_| {
+        if !is_quiet {
+            out!("{label}: ✔");
+        }
+    });
+
+    let samples = stats.map_or(1, |stats| stats.samples);
+
+    if !is_quiet {
+        out!("\r");
+        outln!("{label}: ✔{}", format_duration(&duration, samples));
+        print_details(&stats.as_ref().map(format_stats).unwrap_or_default());
+    }
+
+    (
+        parsed,
+        ParseResult {
+            duration,
+            samples,
+            stats,
+            memory,
+        },
+    )
+}
+
+pub fn run_part<I: Clone, T: PartOutput>(
+    func: impl Fn(I) -> T,
+    input: I,
+    part: u8,
+    options: &RunOptions,
+) -> PartResult {
+    let part_str = step_label(&format!("Part {part}"), options);
+    let is_quiet = options.format != Format::Text;
+
+    let (result, duration, stats, memory) = run_timed(func, input, options, |result| {
+        if !is_quiet {
+            print_result(&result.answer(), &part_str, "", "");
+        }
+    });
+
+    let result = result.answer();
+    let samples = stats.map_or(1, |stats| stats.samples);
+    let details = stats.as_ref().map(format_stats).unwrap_or_default();
+
+    if !is_quiet {
+        print_result(
+            &result,
+            &part_str,
+            &format_duration(&duration, samples),
+            &details,
+        );
+    }
+
+    let (answer, error) = match result {
+        Ok(answer) => (answer, None),
+        Err(e) => (None, Some(e)),
+    };
+
+    PartResult {
+        part,
+        answer,
+        error,
+        duration,
+        samples,
+        stats,
+        memory,
+    }
+}
+
+/// Run a solution part. The behavior differs depending on whether the run is timed:
+///  1. untimed, the function is executed once.
+///  2. timed, the function is warmed up and then benched (approx. the time budget of execution time or 10 samples, whatever take longer.)
+///
+/// Under the `dhat-heap` feature, the heap usage of the first run is measured as well.
 fn run_timed<I: Clone, T>(
     func: impl Fn(I) -> T,
     input: I,
+    options: &RunOptions,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+) -> (T, Duration, Option<Stats>, Option<Memory>) {
     let timer = Instant::now();
-    let result = {
+    let (result, base_time, memory) = {
         let input = input.clone();
 
         #[cfg(feature = "dhat-heap")]
         let _profiler = dhat::Profiler::new_heap();
 
-        func(input)
+        let result = func(input);
+        (result, timer.elapsed(), heap_usage())
     };
-    let base_time = timer.elapsed();
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+    if options.is_timed {
+        let stats = bench(func, input, &base_time, options);
+        (result, stats.median, Some(stats), memory)
     } else {
-        (base_time, 1)
-    };
+        (result, base_time, None, memory)
+    }
+}
+
+/// Heap usage since the dhat profiler was started.
+#[cfg(feature = "dhat-heap")]
+#[allow(clippy::unnecessary_wraps)]
+fn heap_usage() -> Option<Memory> {
+    let stats = dhat::HeapStats::get();
 
-    (result, run.0, run.1)
+    Some(Memory {
+        peak_bytes: stats.max_bytes as u64,
+        total_bytes: stats.total_bytes,
+        allocations: stats.total_blocks,
+    })
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
+#[cfg(not(feature = "dhat-heap"))]
+fn heap_usage() -> Option<Memory> {
+    None
+}
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+fn bench<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    options: &RunOptions,
+) -> Stats {
+    if options.format == Format::Text {
+        out!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+        let _ = stdout().flush();
+    }
 
     let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+        (options.budget.as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+
+    // warm up caches and branch predictors before taking samples.
+    let warmup_iterations = (bench_iterations / 10).clamp(1, 100);
+
+    for _ in 0..warmup_iterations {
+        black_box(func(black_box(input.clone())));
+    }
 
     let mut timers: Vec<Duration> = vec![];
 
@@ -71,19 +597,61 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    compute_stats(&timers)
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
+/// Computes statistics over a set of samples.
+/// Outliers outside of the Tukey fences (1.5 × IQR beyond the quartiles) are rejected first.
+fn compute_stats(samples: &[Duration]) -> Stats {
+    let mut sorted: Vec<u128> = samples.iter().map(Duration::as_nanos).collect();
+    sorted.sort_unstable();
+
+    let q1 = percentile(&sorted, 0.25);
+    let q3 = percentile(&sorted, 0.75);
+    let fence = (q3 - q1) * 3 / 2;
+    let (low, high) = (q1.saturating_sub(fence), q3 + fence);
+
+    let filtered: Vec<u128> = sorted
+        .iter()
+        .copied()
+        .filter(|x| (low..=high).contains(x))
+        .collect();
+
+    let n = filtered.len() as u128;
+    let mean = filtered.iter().sum::<u128>() / n;
+
+    #[allow(clippy::cast_precision_loss)]
+    let variance = filtered
         .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+        .map(|x| (*x as f64 - mean as f64).powi(2))
+        .sum::<f64>()
+        / n as f64;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let to_duration = |nanos: u128| Duration::from_nanos(nanos as u64);
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    Stats {
+        median: to_duration(percentile(&filtered, 0.5)),
+        mean: to_duration(mean),
+        min: to_duration(filtered[0]),
+        max: to_duration(filtered[filtered.len() - 1]),
+        std_dev: Duration::from_nanos(variance.sqrt() as u64),
+        p95: to_duration(percentile(&filtered, 0.95)),
+        samples: sorted.len() as u128,
+        outliers: (sorted.len() - filtered.len()) as u128,
+    }
+}
+
+/// Nearest-rank percentile of sorted values.
+fn percentile(sorted: &[u128], p: f64) -> u128 {
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    let rank = (p * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
 }
 
 fn format_duration(duration: &Duration, samples: u128) -> String {
@@ -94,76 +662,225 @@ fn format_duration(duration: &Duration, samples: u128) -> String {
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+fn format_stats(stats: &Stats) -> String {
+    format!(
+        "{STATS_PREFIX}{:.1?} · mean {:.1?} · σ {:.1?} · min {:.1?} · max {:.1?} · p95 {:.1?} · {} outliers",
+        stats.median, stats.mean, stats.std_dev, stats.min, stats.max, stats.p95, stats.outliers
+    )
+}
+
+/// Format a number of bytes with a binary unit, e.g. `1.5 KiB`.
+#[allow(clippy::cast_precision_loss)]
+pub(crate) fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+/// Prefix of the line with benchmark statistics printed below a part.
+pub const STATS_PREFIX: &str = "  ↳ median ";
+
+/// Label of the line with the timing of a day's shared parser.
+pub const PARSE_LABEL: &str = "Parse";
+
+/// Label of a step, naming the input if it is not the day's own, e.g. `Part 1 [stdin]`.
+fn step_label(name: &str, options: &RunOptions) -> String {
+    match options.input.label() {
+        Some(source) => format!("{name} [{source}]"),
+        None => name.into(),
+    }
+}
+
+fn print_result(
+    result: &Result<Option<String>, String>,
+    part: &str,
+    duration_str: &str,
+    details: &str,
+) {
     let is_intermediate_result = duration_str.is_empty();
 
     match result {
-        Some(result) => {
-            if result.to_string().contains('\n') {
+        Ok(Some(result)) => {
+            if result.contains('\n') {
                 let str = format!("{part}: ▼ {duration_str}");
                 if is_intermediate_result {
-                    print!("{str}");
+                    out!("{str}");
                 } else {
-                    print!("\r");
-                    println!("{str}");
-                    println!("{result}");
+                    out!("\r");
+                    outln!("{str}");
+                    print_details(details);
+                    outln!("{result}");
                 }
             } else {
                 let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}");
                 if is_intermediate_result {
-                    print!("{str}");
+                    out!("{str}");
                 } else {
-                    print!("\r");
-                    println!("{str}");
+                    out!("\r");
+                    outln!("{str}");
+                    print_details(details);
                 }
             }
         }
-        None => {
+        Ok(None) => {
+            if is_intermediate_result {
+                out!("{part}: ✖");
+            } else {
+                out!("\r");
+                outln!("{part}: ✖             ");
+            }
+        }
+        // the error is printed in place of the answer.
+        Err(e) => {
+            let str = format!("{part}: {ANSI_RED}error{ANSI_RESET} {e}");
             if is_intermediate_result {
-                print!("{part}: ✖");
+                out!("{str}");
             } else {
-                print!("\r");
-                println!("{part}: ✖             ");
+                out!("\r");
+                outln!("{str}{duration_str}");
+                print_details(details);
             }
         }
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
-    let args: Vec<String> = env::args().collect();
+fn print_details(details: &str) {
+    if !details.is_empty() {
+        outln!("{details}");
+    }
+}
+
+/// Submit the answer to one part of a day, if the part has an answer.
+fn submit_part(result: &DayResult, puzzle: Puzzle, part: u8) -> Result<(), Error> {
+    let answer = result
+        .parts
+        .iter()
+        .find(|result| result.part == part)
+        .and_then(|result| result.answer.as_deref());
 
-    if !args.contains(&"--submit".into()) {
-        return None;
+    match answer {
+        Some(answer) => submit_result(answer, puzzle, part),
+        None => {
+            eprintln!("Part {part} has no answer, skipping submission.");
+            Ok(())
+        }
     }
+}
 
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+/// Try to submit one part of the solution if:
+///  1. a session cookie is configured.
+///  2. the answer has not been accepted or rejected before, according to the answer ledger.
+fn submit_result(answer: &str, puzzle: Puzzle, part: u8) -> Result<(), Error> {
+    let Puzzle { year, day } = puzzle;
+    let mut answers = Answers::read_from_file(year)?;
+
+    match answers.check(day, part, answer) {
+        Check::Unknown => {}
+        Check::Accepted => {
+            println!("Answer {answer} was already accepted, skipping submission.");
+            return Ok(());
+        }
+        Check::Solved(correct) => {
+            eprintln!("Part {part} was already solved with answer {correct}, skipping submission.");
+            return Ok(());
+        }
+        Check::Rejected(hint) => {
+            let hint = match hint {
+                Some(Hint::TooHigh) => " as too high",
+                Some(Hint::TooLow) => " as too low",
+                None => "",
+            };
+            eprintln!("Answer {answer} was already rejected{hint}, skipping submission.");
+            return Ok(());
+        }
+        Check::OutOfBounds(Hint::TooHigh, bound) => {
+            eprintln!("Warning: answer {answer} is not below {bound}, which was too high.");
+        }
+        Check::OutOfBounds(Hint::TooLow, bound) => {
+            eprintln!("Warning: answer {answer} is not above {bound}, which was too low.");
+        }
     }
 
-    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
+    let client = aoc_client::AocClient::from_env(year)?;
 
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
-    };
+    println!("Submitting result...");
+    let outcome = client.submit(day, part, answer)?;
+
+    println!("{outcome}");
+    answers.record(day, part, answer, &outcome);
+    answers
+        .store_file(year)
+        .map_err(Error::io("Failed to store answer"))
+}
 
-    if part_submit != part {
-        return None;
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{compute_stats, format_bytes, step_label, InputSource, RunOptions};
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    #[test]
+    fn computes_stats() {
+        let samples: Vec<Duration> = [5, 1, 4, 2, 3].map(Duration::from_nanos).to_vec();
+        let stats = compute_stats(&samples);
+        assert_eq!(stats.median, Duration::from_nanos(3));
+        assert_eq!(stats.mean, Duration::from_nanos(3));
+        assert_eq!(stats.min, Duration::from_nanos(1));
+        assert_eq!(stats.max, Duration::from_nanos(5));
+        assert_eq!(stats.std_dev, Duration::from_nanos(1));
+        assert_eq!(stats.p95, Duration::from_nanos(5));
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn rejects_outliers() {
+        let mut samples: Vec<Duration> = (0..20).map(|_| Duration::from_nanos(100)).collect();
+        samples.push(Duration::from_nanos(10_000));
+        let stats = compute_stats(&samples);
+        assert_eq!(stats.max, Duration::from_nanos(100));
+        assert_eq!(stats.samples, 21);
+        assert_eq!(stats.outliers, 1);
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    #[test]
+    fn labels_input_source() {
+        let label = |input| {
+            step_label(
+                "Part 1",
+                &RunOptions {
+                    input,
+                    ..RunOptions::default()
+                },
+            )
+        };
+
+        assert_eq!(label(InputSource::Default), "Part 1");
+        assert_eq!(label(InputSource::Stdin), "Part 1 [stdin]");
+        assert_eq!(
+            label(InputSource::File(PathBuf::from("edge.txt"))),
+            "Part 1 [edge.txt]"
+        );
+    }
 }
@@ -0,0 +1,108 @@
+/// Templates that `cargo scaffold` creates the module of a day from.
+/// Besides the built-in template in `src/template.txt`, templates are read at runtime from
+/// `templates/<name>.txt`, so they can be added and changed without rebuilding.
+use std::{fs, io, path::PathBuf};
+
+use crate::template::{Day, Year};
+
+static TEMPLATES_DIR: &str = "./templates";
+
+/// Values substituted for the placeholders of a template.
+#[derive(Clone, Debug)]
+pub struct Placeholders {
+    pub year: Year,
+    pub day: Day,
+    pub title: Option<String>,
+    pub return_type: String,
+}
+
+/// Path of a template in the templates directory.
+pub fn get_path(name: &str) -> PathBuf {
+    PathBuf::from(TEMPLATES_DIR).join(format!("{name}.txt"))
+}
+
+/// Read a template from the templates directory.
+pub fn read(name: &str) -> Result<String, io::Error> {
+    fs::read_to_string(get_path(name))
+}
+
+/// Names of the templates in the templates directory, in alphabetical order.
+pub fn available() -> Vec<String> {
+    let Ok(entries) = fs::read_dir(TEMPLATES_DIR) else {
+        return vec![];
+    };
+
+    let mut names: Vec<String> = entries
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter_map(|name| name.strip_suffix(".txt").map(str::to_string))
+        .collect();
+
+    names.sort_unstable();
+    names
+}
+
+/// Substitute the placeholders of a template:
+///  - `%YEAR%`: the year of the event, e.g. `2024`.
+///  - `%DAY%`: the zero-padded day, e.g. `01`.
+///  - `%DAY_NUMBER%`: the day without padding, e.g. `1`.
+///  - `%TITLE%`: the title of the puzzle, or e.g. `Day 1` if unknown.
+///  - `%RETURN_TYPE%`: the type of the answers, e.g. `u32`.
+pub fn render(template: &str, placeholders: &Placeholders) -> String {
+    let day_number = placeholders.day.into_inner().to_string();
+    let title = placeholders
+        .title
+        .clone()
+        .unwrap_or_else(|| format!("Day {day_number}"));
+
+    template
+        .replace("%YEAR%", &placeholders.year.to_string())
+        .replace("%DAY_NUMBER%", &day_number)
+        .replace("%DAY%", &placeholders.day.to_string())
+        .replace("%TITLE%", &title)
+        .replace("%RETURN_TYPE%", &placeholders.return_type)
+}
+
+/// Title of a puzzle from the heading of its description, e.g. `--- Day 1: Historian Hysteria ---`.
+pub fn parse_title(markdown: &str) -> Option<String> {
+    let heading = markdown
+        .lines()
+        .find_map(|line| line.strip_prefix("## --- Day "))?;
+
+    let (_, title) = heading.split_once(": ")?;
+    Some(title.trim_end_matches('-').trim().to_string())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{parse_title, render, Placeholders};
+    use crate::{day, year};
+
+    #[test]
+    fn parses_puzzle_titles() {
+        let markdown = "## --- Day 1: Historian Hysteria ---\n\nThe *Chief Historian*...";
+        assert_eq!(parse_title(markdown), Some("Historian Hysteria".into()));
+        assert_eq!(parse_title("## --- Part Two ---"), None);
+    }
+
+    #[test]
+    fn renders_placeholders() {
+        let template =
+            "//! %TITLE% (%YEAR%)\nsolution!(%DAY_NUMBER%); // %DAY%\nfn f() -> %RETURN_TYPE% {}";
+        let mut placeholders = Placeholders {
+            year: year!(2024),
+            day: day!(7),
+            title: Some("Bridge Repair".into()),
+            return_type: "u64".into(),
+        };
+
+        assert_eq!(
+            render(template, &placeholders),
+            "//! Bridge Repair (2024)\nsolution!(7); // 07\nfn f() -> u64 {}"
+        );
+
+        placeholders.title = None;
+        assert!(render(template, &placeholders).starts_with("//! Day 7 (2024)"));
+    }
+}
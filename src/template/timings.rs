@@ -1,17 +1,69 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{
+    collections::HashMap,
+    fs,
+    io::{self, ErrorKind},
+    str::FromStr,
+    time::Duration,
+};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::runner::{Memory, Stats};
+use crate::template::{Day, Error, Year};
 
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+/// Name of the file in the data directory of a year.
+static TIMINGS_FILE_NAME: &str = "timings.json";
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
 pub struct Timing {
     pub day: Day,
+    /// Time spent in the shared parser, if the day declares one.
+    pub parse: Option<String>,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
+    pub parse_stats: Option<Stats>,
+    pub part_1_stats: Option<Stats>,
+    pub part_2_stats: Option<Stats>,
+    /// Heap usage of a single run, only present if measured with `time --memory`.
+    pub parse_memory: Option<Memory>,
+    pub part_1_memory: Option<Memory>,
+    pub part_2_memory: Option<Memory>,
+    /// Error returned by a part instead of an answer while it was benched.
+    pub part_1_error: Option<String>,
+    pub part_2_error: Option<String>,
     pub total_nanos: f64,
+    /// Previous runs of this day, oldest first.
+    pub history: Vec<HistoryEntry>,
+}
+
+/// Median times of a single benchmark run of a day.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HistoryEntry {
+    /// UTC time of the run, e.g. `2024-12-20T08:15:00Z`.
+    pub date: String,
+    /// Short hash of the git commit that was benched, if known.
+    pub commit: Option<String>,
+    pub parse_nanos: Option<f64>,
+    pub part_1_nanos: Option<f64>,
+    pub part_2_nanos: Option<f64>,
+}
+
+impl HistoryEntry {
+    fn nanos(&self) -> [Option<f64>; 3] {
+        [self.parse_nanos, self.part_1_nanos, self.part_2_nanos]
+    }
+}
+
+/// Labels of the steps of a day, in the order of `HistoryEntry::nanos`.
+const STEPS: [&str; 3] = ["Parse", "Part 1", "Part 2"];
+
+/// A step of a day that got slower than its previous best run.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Regression {
+    pub day: Day,
+    pub step: &'static str,
+    pub nanos: f64,
+    pub best_nanos: f64,
 }
 
 /// Represents benchmark times for a set of days.
@@ -23,26 +75,99 @@ pub struct Timings {
 
 impl Timings {
     /// Dehydrate timings to a JSON file.
-    pub fn store_file(&self) -> Result<(), Error> {
+    pub fn store_file(&self, year: Year) -> Result<(), io::Error> {
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        fs::create_dir_all(year.data_dir())?;
+        let mut file = fs::File::create(year.data_dir().join(TIMINGS_FILE_NAME))?;
         json.format_to(&mut file)
     }
 
     /// Rehydrate timings from a JSON file. If not present, returns empty timings.
-    pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
-            .map_err(|x| x.to_string())
-            .and_then(Timings::try_from)
-            .unwrap_or_default()
+    pub fn read_from_file(year: Year) -> Result<Self, Error> {
+        let path = year.data_dir().join(TIMINGS_FILE_NAME);
+        let context = format!("Failed to read timings from \"{}\"", path.display());
+
+        match fs::read_to_string(&path) {
+            Ok(json) => Timings::try_from(json).map_err(Error::io(&context)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(Error::io(&context)(e)),
+        }
+    }
+
+    /// Append the current numbers of every timing to its history.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn stamp(&mut self, date: &str, commit: Option<&str>) {
+        for timing in &mut self.data {
+            let nanos = |stats: Option<Stats>| stats.map(|s| s.median.as_nanos() as f64);
+
+            timing.history.push(HistoryEntry {
+                date: date.into(),
+                commit: commit.map(String::from),
+                parse_nanos: nanos(timing.parse_stats),
+                part_1_nanos: nanos(timing.part_1_stats),
+                part_2_nanos: nanos(timing.part_2_stats),
+            });
+        }
+    }
+
+    /// Find steps in `new` that are slower than their best run in the history of `self` by more than `threshold` (e.g. `0.1` for 10%).
+    pub fn regressions(&self, new: &Self, threshold: f64) -> Vec<Regression> {
+        let mut regressions = vec![];
+
+        for timing in &new.data {
+            let Some(latest) = timing.history.last() else {
+                continue;
+            };
+
+            let Some(stored) = self.data.iter().find(|t| t.day == timing.day) else {
+                continue;
+            };
+
+            for (i, nanos) in latest.nanos().into_iter().enumerate() {
+                let Some(nanos) = nanos else {
+                    continue;
+                };
+
+                let best = stored
+                    .history
+                    .iter()
+                    .filter_map(|entry| entry.nanos()[i])
+                    .reduce(f64::min);
+
+                if let Some(best_nanos) = best {
+                    if nanos > best_nanos * (1.0 + threshold) {
+                        regressions.push(Regression {
+                            day: timing.day,
+                            step: STEPS[i],
+                            nanos,
+                            best_nanos,
+                        });
+                    }
+                }
+            }
+        }
+
+        regressions
     }
 
     /// Merge two sets of timings, overwriting `self` with `other` if present.
+    /// The history of a day is kept, with the runs of `other` appended.
+    /// Heap usage is kept as well, unless `other` measured it again.
     pub fn merge(&self, new: &Self) -> Self {
         let mut data: Vec<Timing> = vec![];
 
         for timing in &new.data {
-            data.push(timing.clone());
+            let mut timing = timing.clone();
+            if let Some(old) = self.data.iter().find(|t| t.day == timing.day) {
+                timing.history = [old.history.clone(), timing.history].concat();
+
+                if !timing.has_memory() {
+                    timing.parse_memory = old.parse_memory;
+                    timing.part_1_memory = old.part_1_memory;
+                    timing.part_2_memory = old.part_2_memory;
+                }
+            }
+            data.push(timing);
         }
 
         for timing in &self.data {
@@ -51,7 +176,7 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|t| t.day);
         Timings { data }
     }
 
@@ -60,10 +185,27 @@ impl Timings {
         self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
     }
 
-    pub fn is_day_complete(&self, day: Day) -> bool {
-        self.data
-            .iter()
-            .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
+    /// Whether all parts of a day were benched without an error. The last day of an event only has a first part.
+    pub fn is_day_complete(&self, year: Year, day: Day) -> bool {
+        let is_last_day = day == year.last_day();
+        self.data.iter().any(|t| {
+            t.day == day
+                && t.part_1.is_some()
+                && t.part_1_error.is_none()
+                && ((t.part_2.is_some() && t.part_2_error.is_none()) || is_last_day)
+        })
+    }
+}
+
+impl Timing {
+    /// Whether heap usage was measured for any step of the day.
+    pub fn has_memory(&self) -> bool {
+        self.memory().iter().any(Option::is_some)
+    }
+
+    /// Heap usage of the steps of a day, in the order of `STEPS`.
+    pub fn memory(&self) -> [Option<Memory>; 3] {
+        [self.parse_memory, self.part_1_memory, self.part_2_memory]
     }
 }
 
@@ -133,6 +275,38 @@ impl From<&Timing> for JsonValue {
             },
         );
 
+        map.insert(
+            "parse".into(),
+            match value.parse.clone() {
+                Some(x) => JsonValue::String(x),
+                None => JsonValue::Null,
+            },
+        );
+
+        map.insert(
+            "history".into(),
+            JsonValue::Array(value.history.iter().map(JsonValue::from).collect()),
+        );
+
+        map.insert("parse_stats".into(), stats_to_json(value.parse_stats));
+        map.insert("part_1_stats".into(), stats_to_json(value.part_1_stats));
+        map.insert("part_2_stats".into(), stats_to_json(value.part_2_stats));
+
+        map.insert("parse_memory".into(), memory_to_json(value.parse_memory));
+        map.insert("part_1_memory".into(), memory_to_json(value.part_1_memory));
+        map.insert("part_2_memory".into(), memory_to_json(value.part_2_memory));
+
+        // only errored parts are marked, so timings without errors keep their shape.
+        let errors = [(1, &value.part_1_error), (2, &value.part_2_error)];
+        for (part, error) in errors {
+            if let Some(error) = error {
+                map.insert(
+                    format!("part_{part}_error"),
+                    JsonValue::String(error.clone()),
+                );
+            }
+        }
+
         JsonValue::Object(map)
     }
 }
@@ -166,18 +340,228 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|v| v.get::<f64>().copied())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
+        // parse times and statistics are optional to support timings stored by older versions.
+        let parse = match json.get("parse") {
+            None | Some(JsonValue::Null) => None,
+            Some(v) => Some(
+                v.get::<String>()
+                    .ok_or("Expected timing.parse to be null or string.")?
+                    .clone(),
+            ),
+        };
+
+        let parse_stats = json.get("parse_stats").map_or(Ok(None), stats_from_json)?;
+
+        let history = match json.get("history") {
+            None => vec![],
+            Some(v) => v
+                .get::<Vec<JsonValue>>()
+                .ok_or("Expected timing.history to be an array.")?
+                .iter()
+                .map(HistoryEntry::try_from)
+                .collect::<Result<_, _>>()?,
+        };
+        let part_1_stats = json.get("part_1_stats").map_or(Ok(None), stats_from_json)?;
+        let part_2_stats = json.get("part_2_stats").map_or(Ok(None), stats_from_json)?;
+
+        let memory = |key: &str| json.get(key).map_or(Ok(None), memory_from_json);
+        let parse_memory = memory("parse_memory")?;
+        let part_1_memory = memory("part_1_memory")?;
+        let part_2_memory = memory("part_2_memory")?;
+
+        let error = |key: &str| match json.get(key) {
+            None | Some(JsonValue::Null) => Ok(None),
+            Some(v) => v
+                .get::<String>()
+                .cloned()
+                .map(Some)
+                .ok_or(format!("Expected timing.{key} to be null or string.")),
+        };
+        let part_1_error = error("part_1_error")?;
+        let part_2_error = error("part_2_error")?;
+
         Ok(Timing {
             day,
+            parse,
             part_1: part_1.cloned(),
             part_2: part_2.cloned(),
+            parse_stats,
+            part_1_stats,
+            part_2_stats,
+            parse_memory,
+            part_1_memory,
+            part_2_memory,
+            part_1_error,
+            part_2_error,
             total_nanos,
+            history,
         })
     }
 }
 
 /* -------------------------------------------------------------------------- */
 
-#[cfg(feature = "test_lib")]
+impl From<&HistoryEntry> for JsonValue {
+    fn from(value: &HistoryEntry) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+        let optional = |x: Option<f64>| x.map_or(JsonValue::Null, JsonValue::Number);
+
+        map.insert("date".into(), JsonValue::String(value.date.clone()));
+        map.insert(
+            "commit".into(),
+            value
+                .commit
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert("parse_nanos".into(), optional(value.parse_nanos));
+        map.insert("part_1_nanos".into(), optional(value.part_1_nanos));
+        map.insert("part_2_nanos".into(), optional(value.part_2_nanos));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for HistoryEntry {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected history entry to be a JSON object.")?;
+
+        let date = json
+            .get("date")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected history.date to be a string.")?;
+
+        let commit = json
+            .get("commit")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected history.commit to be null or string.")?;
+
+        let nanos = |key: &str| match json.get(key) {
+            None | Some(JsonValue::Null) => Ok(None),
+            Some(v) => v
+                .get::<f64>()
+                .copied()
+                .map(Some)
+                .ok_or(format!("Expected history.{key} to be null or a number.")),
+        };
+
+        Ok(HistoryEntry {
+            date: date.clone(),
+            commit: commit.cloned(),
+            parse_nanos: nanos("parse_nanos")?,
+            part_1_nanos: nanos("part_1_nanos")?,
+            part_2_nanos: nanos("part_2_nanos")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[allow(clippy::cast_precision_loss)]
+pub(super) fn stats_to_json(stats: Option<Stats>) -> JsonValue {
+    let Some(stats) = stats else {
+        return JsonValue::Null;
+    };
+
+    let mut map: HashMap<String, JsonValue> = HashMap::new();
+    let nanos = |d: Duration| JsonValue::Number(d.as_nanos() as f64);
+
+    map.insert("median_nanos".into(), nanos(stats.median));
+    map.insert("mean_nanos".into(), nanos(stats.mean));
+    map.insert("min_nanos".into(), nanos(stats.min));
+    map.insert("max_nanos".into(), nanos(stats.max));
+    map.insert("std_dev_nanos".into(), nanos(stats.std_dev));
+    map.insert("p95_nanos".into(), nanos(stats.p95));
+    map.insert("samples".into(), JsonValue::Number(stats.samples as f64));
+    map.insert("outliers".into(), JsonValue::Number(stats.outliers as f64));
+
+    JsonValue::Object(map)
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub(super) fn stats_from_json(value: &JsonValue) -> Result<Option<Stats>, String> {
+    if value.is_null() {
+        return Ok(None);
+    }
+
+    let json = value
+        .get::<HashMap<String, JsonValue>>()
+        .ok_or("Expected timing stats to be null or a JSON object.")?;
+
+    let number = |key: &str| {
+        json.get(key)
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or(format!("Expected timing stats `{key}` to be a number."))
+    };
+    let duration = |key: &str| number(key).map(|n| Duration::from_nanos(n as u64));
+
+    Ok(Some(Stats {
+        median: duration("median_nanos")?,
+        mean: duration("mean_nanos")?,
+        min: duration("min_nanos")?,
+        max: duration("max_nanos")?,
+        std_dev: duration("std_dev_nanos")?,
+        p95: duration("p95_nanos")?,
+        samples: number("samples")? as u128,
+        outliers: number("outliers")? as u128,
+    }))
+}
+
+#[allow(clippy::cast_precision_loss)]
+pub(super) fn memory_to_json(memory: Option<Memory>) -> JsonValue {
+    let Some(memory) = memory else {
+        return JsonValue::Null;
+    };
+
+    let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+    map.insert(
+        "peak_bytes".into(),
+        JsonValue::Number(memory.peak_bytes as f64),
+    );
+    map.insert(
+        "total_bytes".into(),
+        JsonValue::Number(memory.total_bytes as f64),
+    );
+    map.insert(
+        "allocations".into(),
+        JsonValue::Number(memory.allocations as f64),
+    );
+
+    JsonValue::Object(map)
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub(super) fn memory_from_json(value: &JsonValue) -> Result<Option<Memory>, String> {
+    if value.is_null() {
+        return Ok(None);
+    }
+
+    let json = value
+        .get::<HashMap<String, JsonValue>>()
+        .ok_or("Expected memory to be null or a JSON object.")?;
+
+    let number = |key: &str| {
+        json.get(key)
+            .and_then(|v| v.get::<f64>().copied())
+            .map(|n| n as u64)
+            .ok_or(format!("Expected memory `{key}` to be a number."))
+    };
+
+    Ok(Some(Memory {
+        peak_bytes: number("peak_bytes")?,
+        total_bytes: number("total_bytes")?,
+        allocations: number("allocations")?,
+    }))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
 mod tests {
     use crate::day;
 
@@ -190,18 +574,48 @@ mod tests {
                     day: day!(1),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse: None,
+                    parse_stats: None,
+                    parse_memory: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    part_1_error: None,
+                    part_2_error: None,
+                    history: vec![],
                     total_nanos: 3e+10,
                 },
                 Timing {
                     day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse: None,
+                    parse_stats: None,
+                    parse_memory: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    part_1_error: None,
+                    part_2_error: None,
+                    history: vec![],
                     total_nanos: 7e+10,
                 },
                 Timing {
                     day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse: None,
+                    parse_stats: None,
+                    parse_memory: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    part_1_error: None,
+                    part_2_error: None,
+                    history: vec![],
                     total_nanos: 4e+10,
                 },
             ],
@@ -209,7 +623,7 @@ mod tests {
     }
 
     mod deserialization {
-        use crate::{day, template::timings::Timings};
+        use crate::{day, template::timings::Timings, year};
 
         #[test]
         fn handles_json_timings() {
@@ -223,6 +637,46 @@ mod tests {
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
         }
 
+        #[test]
+        fn handles_parse_timings() {
+            let json = r#"{ "data": [{ "day": "12", "parse": "2ms", "part_1": "1ns", "part_2": "1ns", "total_nanos": 2000002 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.parse, Some("2ms".to_string()));
+            assert_eq!(timing.parse_stats, None);
+        }
+
+        #[test]
+        fn handles_history() {
+            let json = r#"{ "data": [{ "day": "20", "part_1": "1ms", "part_2": null, "total_nanos": 1000000, "history": [{ "date": "2024-12-20T08:15:00Z", "commit": "abc1234", "parse_nanos": null, "part_1_nanos": 1000000, "part_2_nanos": null }] }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let history = &timings.data.first().unwrap().history;
+            assert_eq!(history.len(), 1);
+            assert_eq!(history[0].commit, Some("abc1234".into()));
+            assert_eq!(history[0].part_1_nanos, Some(1_000_000_f64));
+            assert_eq!(history[0].part_2_nanos, None);
+        }
+
+        #[test]
+        fn handles_memory() {
+            let json = r#"{ "data": [{ "day": "05", "part_1": "1ms", "part_2": null, "total_nanos": 1000000, "part_1_memory": { "peak_bytes": 2048, "total_bytes": 4096, "allocations": 12 }, "part_2_memory": null }] }"#.to_string();
+            let timing = Timings::try_from(json).unwrap().data.remove(0);
+            assert_eq!(timing.parse_memory, None);
+            assert_eq!(timing.part_1_memory.unwrap().peak_bytes, 2048);
+            assert_eq!(timing.part_1_memory.unwrap().allocations, 12);
+            assert_eq!(timing.part_2_memory, None);
+        }
+
+        #[test]
+        fn handles_errors() {
+            let json = r#"{ "data": [{ "day": "18", "part_1": "1ms", "part_2": "2ms", "total_nanos": 3000000, "part_2_error": "no path found" }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.part_1_error, None);
+            assert_eq!(timing.part_2_error.as_deref(), Some("no path found"));
+            assert!(!timings.is_day_complete(year!(2024), day!(18)));
+        }
+
         #[test]
         fn handles_empty_timings() {
             let json = r#"{ "data": [] }"#.to_string();
@@ -253,7 +707,7 @@ mod tests {
         #[test]
         fn serializes_timings() {
             let timings = get_mock_timings();
-            let value = JsonValue::try_from(timings).unwrap();
+            let value = JsonValue::from(timings);
             assert_eq!(
                 value
                     .get::<HashMap<String, JsonValue>>()
@@ -272,6 +726,7 @@ mod tests {
         use crate::{
             day,
             template::timings::{Timing, Timings},
+            year,
         };
 
         #[test]
@@ -281,11 +736,21 @@ mod tests {
                     day: day!(1),
                     part_1: Some("1ms".into()),
                     part_2: Some("2ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse: None,
+                    parse_stats: None,
+                    parse_memory: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    part_1_error: None,
+                    part_2_error: None,
+                    history: vec![],
                     total_nanos: 3_000_000_000_f64,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert!(timings.is_day_complete(year!(2024), day!(1)));
         }
 
         #[test]
@@ -295,11 +760,46 @@ mod tests {
                     day: day!(1),
                     part_1: Some("1ms".into()),
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse: None,
+                    parse_stats: None,
+                    parse_memory: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    part_1_error: None,
+                    part_2_error: None,
+                    history: vec![],
                     total_nanos: 1_000_000_000_f64,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert!(!timings.is_day_complete(year!(2024), day!(1)));
+        }
+
+        #[test]
+        fn handles_last_days() {
+            let timings = Timings {
+                data: vec![Timing {
+                    day: day!(12),
+                    part_1: Some("1ms".into()),
+                    part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse: None,
+                    parse_stats: None,
+                    parse_memory: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    part_1_error: None,
+                    part_2_error: None,
+                    history: vec![],
+                    total_nanos: 1_000_000_000_f64,
+                }],
+            };
+
+            assert!(timings.is_day_complete(year!(2025), day!(12)));
+            assert!(!timings.is_day_complete(year!(2024), day!(12)));
         }
 
         #[test]
@@ -309,17 +809,93 @@ mod tests {
                     day: day!(1),
                     part_1: None,
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse: None,
+                    parse_stats: None,
+                    parse_memory: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    part_1_error: None,
+                    part_2_error: None,
+                    history: vec![],
                     total_nanos: 0.0,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert!(!timings.is_day_complete(year!(2024), day!(1)));
+        }
+    }
+
+    mod regressions {
+        use crate::{
+            day,
+            template::timings::{HistoryEntry, Regression, Timing, Timings},
+        };
+
+        fn get_timings(part_1_nanos: &[f64]) -> Timings {
+            Timings {
+                data: vec![Timing {
+                    day: day!(20),
+                    part_1: None,
+                    part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse: None,
+                    parse_stats: None,
+                    parse_memory: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    part_1_error: None,
+                    part_2_error: None,
+                    history: part_1_nanos
+                        .iter()
+                        .map(|nanos| HistoryEntry {
+                            date: "2024-12-20T00:00:00Z".into(),
+                            commit: None,
+                            parse_nanos: None,
+                            part_1_nanos: Some(*nanos),
+                            part_2_nanos: None,
+                        })
+                        .collect(),
+                    total_nanos: 0_f64,
+                }],
+            }
+        }
+
+        #[test]
+        fn detects_regressions() {
+            let stored = get_timings(&[300.0, 100.0, 200.0]);
+            let regressions = stored.regressions(&get_timings(&[120.0]), 0.1);
+            assert_eq!(
+                regressions,
+                vec![Regression {
+                    day: day!(20),
+                    step: "Part 1",
+                    nanos: 120.0,
+                    best_nanos: 100.0,
+                }]
+            );
+        }
+
+        #[test]
+        fn ignores_changes_below_threshold() {
+            let stored = get_timings(&[100.0]);
+            assert_eq!(stored.regressions(&get_timings(&[105.0]), 0.1), vec![]);
+            assert_eq!(stored.regressions(&get_timings(&[50.0]), 0.1), vec![]);
+        }
+
+        #[test]
+        fn ignores_days_without_history() {
+            let stored = get_timings(&[]);
+            assert_eq!(stored.regressions(&get_timings(&[500.0]), 0.1), vec![]);
         }
     }
 
     mod merge {
         use crate::{
             day,
+            template::runner::Memory,
             template::timings::{Timing, Timings},
         };
 
@@ -333,6 +909,16 @@ mod tests {
                     day: day!(3),
                     part_1: None,
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse: None,
+                    parse_stats: None,
+                    parse_memory: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    part_1_error: None,
+                    part_2_error: None,
+                    history: vec![],
                     total_nanos: 0_f64,
                 }],
             };
@@ -353,6 +939,16 @@ mod tests {
                     day: day!(2),
                     part_1: None,
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse: None,
+                    parse_stats: None,
+                    parse_memory: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    part_1_error: None,
+                    part_2_error: None,
+                    history: vec![],
                     total_nanos: 0_f64,
                 }],
             };
@@ -373,6 +969,35 @@ mod tests {
             assert_eq!(merged.data.len(), 3);
         }
 
+        #[test]
+        fn keeps_history() {
+            let mut timings = get_mock_timings();
+            timings.stamp("2024-12-01T00:00:00Z", Some("abc1234"));
+
+            let mut other = get_mock_timings();
+            other.stamp("2024-12-02T00:00:00Z", None);
+
+            let merged = timings.merge(&other);
+            let history = &merged.data[0].history;
+            assert_eq!(history.len(), 2);
+            assert_eq!(history[0].commit, Some("abc1234".into()));
+            assert_eq!(history[1].date, "2024-12-02T00:00:00Z");
+        }
+
+        #[test]
+        fn keeps_memory() {
+            let mut timings = get_mock_timings();
+            timings.data[0].part_1_memory = Some(Memory {
+                peak_bytes: 1024,
+                total_bytes: 2048,
+                allocations: 3,
+            });
+
+            let merged = timings.merge(&get_mock_timings());
+            assert_eq!(merged.data[0].part_1_memory, timings.data[0].part_1_memory);
+            assert!(!merged.data[1].has_memory());
+        }
+
         #[test]
         fn handles_empty_other_timings() {
             let timings = get_mock_timings();
@@ -0,0 +1,127 @@
+/// Reads `aoc.toml` with the `toml` crate into a flat table of settings. Keys of tables are
+/// flattened, e.g. `budget` in `[bench]` is read as `bench.budget`.
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+/// A value of a key.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    String(String),
+    Integer(i64),
+    Boolean(bool),
+    /// Any other value, e.g. an array or a float. No setting accepts these.
+    Other(::toml::Value),
+}
+
+/// Keys of a document with their values, keyed by `table.key`.
+pub type Table = BTreeMap<String, Value>;
+
+impl Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::String(s) => write!(f, "{s:?}"),
+            Value::Integer(n) => write!(f, "{n}"),
+            Value::Boolean(b) => write!(f, "{b}"),
+            Value::Other(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+/// Parse a document. Errors name the line they occurred on.
+pub fn parse(document: &str) -> Result<Table, String> {
+    let document: ::toml::Table = ::toml::from_str(document).map_err(|e| {
+        let message = e.message().to_string();
+        match e.span() {
+            Some(span) => {
+                let line = document[..span.start].matches('\n').count() + 1;
+                format!("line {line}: {message}")
+            }
+            None => message,
+        }
+    })?;
+
+    let mut table = Table::new();
+    flatten(&mut table, "", document);
+    Ok(table)
+}
+
+/// Insert the keys of a table and of its nested tables, prefixed with the names of the tables.
+fn flatten(table: &mut Table, prefix: &str, document: ::toml::Table) {
+    for (key, value) in document {
+        let key = format!("{prefix}{key}");
+
+        let value = match value {
+            ::toml::Value::Table(nested) => {
+                flatten(table, &format!("{key}."), nested);
+                continue;
+            }
+            ::toml::Value::String(s) => Value::String(s),
+            ::toml::Value::Integer(n) => Value::Integer(n),
+            ::toml::Value::Boolean(b) => Value::Boolean(b),
+            value => Value::Other(value),
+        };
+
+        table.insert(key, value);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{parse, Value};
+
+    #[test]
+    fn parses_tables_and_values() {
+        let table = parse(
+            "# settings\n\
+             year = 2024\n\
+             \n\
+             [readme]\n\
+             path = \"docs/README.md\" # where the table goes\n\
+             marker = 'bench # table'\n\
+             \n\
+             [days]\n\
+             2025 = 1_2\n\
+             enabled = false\n",
+        )
+        .unwrap();
+
+        assert_eq!(table["year"], Value::Integer(2024));
+        assert_eq!(table["readme.path"], Value::String("docs/README.md".into()));
+        assert_eq!(
+            table["readme.marker"],
+            Value::String("bench # table".into())
+        );
+        assert_eq!(table["days.2025"], Value::Integer(12));
+        assert_eq!(table["days.enabled"], Value::Boolean(false));
+        assert_eq!(parse("offset = -5").unwrap()["offset"], Value::Integer(-5));
+    }
+
+    #[test]
+    fn parses_quoted_keys_and_inline_tables() {
+        let table = parse(
+            "\"year\" = 2024\n\
+             bench = { budget = 500 }\n\
+             [days]\n\
+             \"2025\" = 12\n\
+             skip = [1, 2]\n",
+        )
+        .unwrap();
+
+        assert_eq!(table["year"], Value::Integer(2024));
+        assert_eq!(table["bench.budget"], Value::Integer(500));
+        assert_eq!(table["days.2025"], Value::Integer(12));
+        assert!(matches!(table["days.skip"], Value::Other(_)));
+    }
+
+    #[test]
+    fn rejects_invalid_documents() {
+        assert!(parse("year = 2024\nyear = 2025")
+            .unwrap_err()
+            .starts_with("line 2:"));
+        assert!(parse("[bench").unwrap_err().starts_with("line 1:"));
+        assert!(parse("budget").is_err());
+        assert!(parse("path = \"unterminated").is_err());
+    }
+}
@@ -0,0 +1,225 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+#[cfg(feature = "today")]
+use chrono::{Datelike, FixedOffset, Utc};
+
+use crate::template::{config, Day};
+
+/// The year of the first Advent of Code.
+const FIRST_YEAR: u16 = 2015;
+
+/// A valid year of Advent of Code (i.e. 2015 or later).
+///
+/// # Display
+/// This value displays as a four digit number.
+///
+/// ```
+/// # use advent_of_code::template::Year;
+/// let year = Year::new(2024).unwrap();
+/// assert_eq!(year.to_string(), "2024")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if it's a year of Advent of Code,
+    /// returns [`None`] otherwise.
+    pub fn new(year: u16) -> Option<Self> {
+        if year < FIRST_YEAR {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __new_unchecked(year: u16) -> Self {
+        Self(year)
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+
+    /// Number of days of the event: 25 until 2024, 12 since 2025, unless configured otherwise.
+    pub fn days(self) -> u8 {
+        config::get().days(self)
+    }
+
+    /// The last day of the event, which has a single puzzle.
+    pub fn last_day(self) -> Day {
+        Day::__new_unchecked(self.days())
+    }
+
+    /// Whether a day is part of the event.
+    pub fn has_day(self, day: Day) -> bool {
+        day <= self.days()
+    }
+
+    /// Directory holding the inputs, examples, puzzles, answers and timings of the year.
+    pub fn data_dir(self) -> PathBuf {
+        config::get().data_dir.join(self.to_string())
+    }
+}
+
+#[cfg(feature = "today")]
+impl Year {
+    /// Returns the year of the event running on the current day, `None` outside of december.
+    pub fn today() -> Option<Self> {
+        let offset = FixedOffset::east_opt(config::get().server_utc_offset * 3600)?;
+        let today = Utc::now().with_timezone(&offset);
+        if today.month() == 12 {
+            Self::new(u16::try_from(today.year()).ok()?)
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}", self.0)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a year of advent of code, 2015 or later")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A single puzzle, identified by the year of its event and its day.
+///
+/// # Display
+/// This value displays as the name of the puzzle's binary, e.g. `2024-08`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Puzzle {
+    pub year: Year,
+    pub day: Day,
+}
+
+impl Puzzle {
+    pub const fn new(year: Year, day: Day) -> Self {
+        Self { year, day }
+    }
+
+    /// Creates a [`Puzzle`] if the day is part of the event of the year, returns [`None`] otherwise.
+    pub fn try_new(year: Year, day: Day) -> Option<Self> {
+        year.has_day(day).then_some(Self { year, day })
+    }
+
+    /// Path of the module of the puzzle's solution, e.g. `./src/bin/2024-08.rs`.
+    pub fn bin_path(self) -> String {
+        format!("./src/bin/{self}.rs")
+    }
+
+    /// Path of the puzzle's input, e.g. `./data/2024/inputs/08.txt`.
+    pub fn input_path(self) -> PathBuf {
+        self.year
+            .data_dir()
+            .join("inputs")
+            .join(format!("{}.txt", self.day))
+    }
+
+    /// Path of the puzzle's description, e.g. `./data/2024/puzzles/08.md`.
+    pub fn puzzle_path(self) -> PathBuf {
+        self.year
+            .data_dir()
+            .join("puzzles")
+            .join(format!("{}.md", self.day))
+    }
+}
+
+#[cfg(feature = "today")]
+impl Puzzle {
+    /// Returns the puzzle of the current day if it's a day of this year's event, `None` otherwise.
+    pub fn today() -> Option<Self> {
+        Some(Self::new(Year::today()?, Day::today()?))
+    }
+}
+
+impl Display for Puzzle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.year, self.day)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Year`] value in a const context.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {{
+        const _ASSERT: () = assert!(
+            $year >= 2015,
+            concat!(
+                "invalid year `",
+                $year,
+                "`, expecting a year of advent of code, 2015 or later"
+            ),
+        );
+        $crate::template::Year::__new_unchecked($year)
+    }};
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{Puzzle, Year};
+    use crate::day;
+    use std::path::PathBuf;
+
+    #[test]
+    fn parses_years() {
+        assert_eq!("2024".parse::<Year>().ok(), Some(year!(2024)));
+        assert!("2014".parse::<Year>().is_err());
+        assert!("24".parse::<Year>().is_err());
+    }
+
+    #[test]
+    fn knows_the_days_of_events() {
+        assert_eq!(year!(2024).days(), 25);
+        assert_eq!(year!(2025).last_day(), day!(12));
+        assert!(Puzzle::try_new(year!(2024), day!(20)).is_some());
+        assert!(Puzzle::try_new(year!(2025), day!(20)).is_none());
+    }
+
+    #[test]
+    fn namespaces_puzzle_paths() {
+        let puzzle = Puzzle::new(year!(2023), day!(8));
+        assert_eq!(puzzle.to_string(), "2023-08");
+        assert_eq!(puzzle.bin_path(), "./src/bin/2023-08.rs");
+        assert_eq!(
+            puzzle.input_path(),
+            PathBuf::from("./data/2023/inputs/08.txt")
+        );
+        assert_eq!(
+            puzzle.puzzle_path(),
+            PathBuf::from("./data/2023/puzzles/08.md")
+        );
+    }
+}